@@ -41,11 +41,24 @@ fn main() {
         _ => "".to_string(),
     };
 
+    let target_os = match std::env::var("CARGO_CFG_TARGET_OS") {
+        Ok(os) => os,
+        _ => "".to_string(),
+    };
+
     // Backend overrides / defaults
     let curve25519_dalek_backend =
         match std::env::var("CARGO_CFG_CURVE25519_DALEK_BACKEND").as_deref() {
             Ok("fiat") => "fiat",
             Ok("serial") => "serial",
+            Ok("risc0-emulated") => "risc0-emulated",
+            Ok("risc0") => {
+                // risc0 can only be enabled inside the RISC Zero zkVM, where the precompile exists
+                match is_capable_risc0(&target_arch, &target_os) {
+                    true => "risc0",
+                    false => panic!("Could not override curve25519_dalek_backend to risc0"),
+                }
+            }
             Ok("simd") => {
                 // simd can only be enabled on x86_64 & 64bit target_pointer_width
                 match is_capable_simd(&target_arch, curve25519_dalek_bits) {
@@ -55,10 +68,15 @@ fn main() {
                     false => panic!("Could not override curve25519_dalek_backend to simd"),
                 }
             }
-            // default between serial / simd (if potentially capable)
-            _ => match is_capable_simd(&target_arch, curve25519_dalek_bits) {
-                true => "simd",
-                false => "serial",
+            // default to risc0 inside the zkVM, otherwise between serial / simd (if potentially
+            // capable)
+            _ => match (
+                is_capable_risc0(&target_arch, &target_os),
+                is_capable_simd(&target_arch, curve25519_dalek_bits),
+            ) {
+                (true, _) => "risc0",
+                (false, true) => "simd",
+                (false, false) => "serial",
             },
         };
     println!("cargo:rustc-cfg=curve25519_dalek_backend=\"{curve25519_dalek_backend}\"");
@@ -69,6 +87,11 @@ fn is_capable_simd(arch: &str, bits: DalekBits) -> bool {
     arch == "x86_64" && bits == DalekBits::Dalek64
 }
 
+// Is the target the RISC Zero zkVM, which provides the bigint precompile ?
+fn is_capable_risc0(arch: &str, os: &str) -> bool {
+    arch == "riscv32" && os == "zkvm"
+}
+
 // Deterministic cfg(curve25519_dalek_bits) when this is not explicitly set.
 mod deterministic {
 
@@ -135,7 +135,7 @@ use zeroize::Zeroize;
 use crate::constants;
 
 use crate::edwards::EdwardsPoint;
-use crate::field::FieldElement;
+use crate::field::BackendFieldElement as FieldElement;
 use crate::traits::ValidityCheck;
 
 // ------------------------------------------------------------------------
@@ -387,12 +387,16 @@ impl ProjectivePoint {
         let YY_minus_XX = &YY - &XX;
 
         cfg_if::cfg_if! {
-            if #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))] {
+            if #[cfg(any(
+                curve25519_dalek_backend = "risc0",
+                curve25519_dalek_backend = "risc0-emulated"
+            ))] {
                 // According to https://en.wikipedia.org/wiki/Edwards_curve#Doubling,
                 // (x + y)^2 - x^2 - y^2 is used as an optimization for computing 2xy.
                 // However, multiplication is faster inside the zkvm so we compute
-                // 2xy directly instead.
-                let new_x = &(&FieldElement::TWO * &self.X) * &self.Y;
+                // 2xy directly instead, doubling with an addition.
+                let XY = &self.X * &self.Y;
+                let new_x = &XY + &XY;
             } else {
                 let X_plus_Y = &self.X + &self.Y;
                 let X_plus_Y_sq = X_plus_Y.square();
@@ -431,13 +435,7 @@ impl<'a, 'b> Add<&'b ProjectiveNielsPoint> for &'a EdwardsPoint {
         let TT2d = &self.T * &other.T2d;
         let ZZ = &self.Z * &other.Z;
 
-        cfg_if::cfg_if! {
-            if #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))] {
-                let ZZ2 = &FieldElement::TWO * &ZZ;
-            } else {
-                let ZZ2 = &ZZ + &ZZ;
-            }
-        }
+        let ZZ2 = &ZZ + &ZZ;
 
         CompletedPoint {
             X: &PP - &MM,
@@ -460,13 +458,7 @@ impl<'a, 'b> Sub<&'b ProjectiveNielsPoint> for &'a EdwardsPoint {
         let TT2d = &self.T * &other.T2d;
         let ZZ = &self.Z * &other.Z;
 
-        cfg_if::cfg_if! {
-            if #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))] {
-                let ZZ2 = &FieldElement::TWO * &ZZ;
-            } else {
-                let ZZ2 = &ZZ + &ZZ;
-            }
-        }
+        let ZZ2 = &ZZ + &ZZ;
 
         CompletedPoint {
             X: &PM - &MP,
@@ -488,13 +480,7 @@ impl<'a, 'b> Add<&'b AffineNielsPoint> for &'a EdwardsPoint {
         let MM = &Y_minus_X * &other.y_minus_x;
         let Txy2d = &self.T * &other.xy2d;
 
-        cfg_if::cfg_if! {
-            if #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))] {
-                let Z2 = &FieldElement::TWO * &self.Z;
-            } else {
-                let Z2 = &self.Z + &self.Z;
-            }
-        }
+        let Z2 = &self.Z + &self.Z;
 
         CompletedPoint {
             X: &PP - &MM,
@@ -516,13 +502,7 @@ impl<'a, 'b> Sub<&'b AffineNielsPoint> for &'a EdwardsPoint {
         let MP = &Y_minus_X * &other.y_plus_x;
         let Txy2d = &self.T * &other.xy2d;
 
-        cfg_if::cfg_if! {
-            if #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))] {
-                let Z2 = &FieldElement::TWO * &self.Z;
-            } else {
-                let Z2 = &self.Z + &self.Z;
-            }
-        }
+        let Z2 = &self.Z + &self.Z;
 
         CompletedPoint {
             X: &PM - &MP,
@@ -49,7 +49,7 @@ use fiat_crypto::curve25519_32::*;
 /// # Note
 ///
 /// The `curve25519_dalek::field` module provides a type alias
-/// `curve25519_dalek::field::FieldElement` to either `FieldElement51`
+/// `curve25519_dalek::field::BackendFieldElement` to either `FieldElement51`
 /// or `FieldElement2625`.
 ///
 /// The backend-specific type `FieldElement2625` should not be used
@@ -38,7 +38,7 @@ use fiat_crypto::curve25519_64::*;
 /// # Note
 ///
 /// The `curve25519_dalek::field` module provides a type alias
-/// `curve25519_dalek::field::FieldElement` to either `FieldElement51`
+/// `curve25519_dalek::field::BackendFieldElement` to either `FieldElement51`
 /// or `FieldElement2625`.
 ///
 /// The backend-specific type `FieldElement51` should not be used
@@ -31,10 +31,17 @@ cfg_if! {
         #[doc(hidden)]
         pub mod fiat_u64;
 
-    } else if #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))] {
+    } else if #[cfg(any(
+        curve25519_dalek_backend = "risc0",
+        curve25519_dalek_backend = "risc0-emulated"
+    ))] {
 
         pub mod risc0;
 
+        // Reference backend for the differential tests in `risc0::differential`.
+        #[cfg(all(test, curve25519_dalek_backend = "risc0-emulated"))]
+        pub(crate) mod u64;
+
     } else {
 
         #[cfg(curve25519_dalek_bits = "32")]
@@ -49,6 +49,40 @@ pub(crate) const SQRT_M1: FieldElementR0 = FieldElementR0(U256::from_be_hex(
     "2B8324804FC1DF0B2B4D00993DFBD7A72F431806AD2FE478C4EE1B274A0EA0B0",
 ));
 
+/// `SQRT_ID` is the nonnegative square root of `i*d`, where `i = sqrt(-1)` and `d` is the Edwards
+/// curve parameter. (This is used internally by the inverse of the Ristretto Elligator map.)
+pub(crate) const SQRT_ID: FieldElementR0 = FieldElementR0(U256::from_be_hex(
+    "05B35558F47BEB977534549973EB6E15CDBCA92D0EEA6DAA75302ACB4A5C1BA8",
+));
+
+/// `FIELD_TWO_INV` is the inverse of 2 in the base field, `(p+1)/2`. (This is used by the `ff`
+/// implementation for `FieldElement`.)
+#[cfg(all(feature = "group", feature = "hazmat"))]
+pub(crate) const FIELD_TWO_INV: FieldElementR0 = FieldElementR0(U256::from_be_hex(
+    "3FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF7",
+));
+
+/// `FIELD_MULTIPLICATIVE_GENERATOR` is 2, which generates the multiplicative group of the base
+/// field. (This is used by the `ff` implementation for `FieldElement`.)
+#[cfg(all(feature = "group", feature = "hazmat"))]
+pub(crate) const FIELD_MULTIPLICATIVE_GENERATOR: FieldElementR0 = FieldElementR0(
+    U256::from_be_hex("0000000000000000000000000000000000000000000000000000000000000002"),
+);
+
+/// `FIELD_ROOT_OF_UNITY_INV` is `-SQRT_M1`, the inverse of the primitive 4th root of unity
+/// `SQRT_M1 = 2^((p-1)/4)`. (This is used by the `ff` implementation for `FieldElement`.)
+#[cfg(all(feature = "group", feature = "hazmat"))]
+pub(crate) const FIELD_ROOT_OF_UNITY_INV: FieldElementR0 = FieldElementR0(U256::from_be_hex(
+    "547CDB7FB03E20F4D4B2FF66C2042858D0BCE7F952D01B873B11E4D8B5F15F3D",
+));
+
+/// `FIELD_DELTA` is `2^4 = 16`, the multiplicative generator raised to `2^S`. (This is used by
+/// the `ff` implementation for `FieldElement`.)
+#[cfg(all(feature = "group", feature = "hazmat"))]
+pub(crate) const FIELD_DELTA: FieldElementR0 = FieldElementR0(U256::from_be_hex(
+    "0000000000000000000000000000000000000000000000000000000000000010",
+));
+
 /// `APLUS2_OVER_FOUR` is (A+2)/4. (This is used internally within the Montgomery ladder.)
 pub(crate) const APLUS2_OVER_FOUR: FieldElementR0 = FieldElementR0(U256::from_be_hex(
     "000000000000000000000000000000000000000000000000000000000001DB42",
@@ -67,6 +101,12 @@ pub(crate) const MONTGOMERY_A_NEG: FieldElementR0 = FieldElementR0(U256::from_be
     "7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF892E7",
 ));
 
+/// `ED25519_SQRTAM2` is the nonnegative square root of -(A+2) = -486664, used by the rational
+/// map from Curve25519 to edwards25519.
+pub(crate) const ED25519_SQRTAM2: FieldElementR0 = FieldElementR0(U256::from_be_hex(
+    "0F26EDF460A006BBD27B08DC03FC4F7EC5A1D3D14B7D1A82CC6E04AAFF457E06",
+));
+
 /// `L` is the order of base point, i.e. 2^252 +
 /// 27742317777372353535851937790883648493
 pub(crate) const L: ScalarR0 = ScalarR0(U256::from_be_hex(
@@ -0,0 +1,123 @@
+//! Counters for the operations made by the R0 backend, enabled by the `risc0-op-counters`
+//! feature.
+//!
+//! Every call to the bigint precompile made by `FieldElementR0` or `ScalarR0` is counted under
+//! the type of operation it was made for. Additions and subtractions are counted as well, even
+//! though they make no precompile call. The counters can be read with [`read`] and cleared with
+//! [`reset`], so a guest program can measure any `EdwardsPoint` or `Scalar` operation:
+//!
+//! ```ignore
+//! use curve25519_dalek::risc0_op_counters;
+//!
+//! risc0_op_counters::reset();
+//! let compressed = point.compress();
+//! let counts = risc0_op_counters::read();
+//! assert!(counts.field.precompile_calls() > 0);
+//! ```
+//!
+//! The counters are global and updated atomically, so no count is lost when several threads do
+//! arithmetic at once under host emulation. They then include the operations of every thread, so
+//! a measurement should be taken while no other thread is doing arithmetic.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// The number of operations of each type made by the field or the scalar arithmetic.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct OpCounts {
+    /// Precompile calls made to multiply, including negations.
+    pub mul: u32,
+    /// Precompile calls made to square.
+    pub square: u32,
+    /// Additions and subtractions with a conditional correction. These make no precompile calls.
+    pub add_with_correction: u32,
+    /// Precompile calls made to reduce a value, including the Montgomery reduction of scalars.
+    pub reduction: u32,
+}
+
+impl OpCounts {
+    /// The total number of precompile calls.
+    pub fn precompile_calls(&self) -> u32 {
+        self.mul + self.square + self.reduction
+    }
+}
+
+/// The operation counts of the field and the scalar arithmetic.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Counts {
+    /// Operations made by field arithmetic modulo \\(p = 2\^{255} - 19\\).
+    pub field: OpCounts,
+    /// Operations made by scalar arithmetic modulo \\(\ell\\).
+    pub scalar: OpCounts,
+}
+
+/// Read the operation counts accumulated since the last call to [`reset`].
+pub fn read() -> Counts {
+    Counts {
+        field: FIELD.read(),
+        scalar: SCALAR.read(),
+    }
+}
+
+/// Set all operation counts to zero.
+pub fn reset() {
+    FIELD.reset();
+    SCALAR.reset();
+}
+
+pub(crate) struct Counter(AtomicU32);
+
+impl Counter {
+    const fn new() -> Counter {
+        Counter(AtomicU32::new(0))
+    }
+
+    pub(crate) fn increment(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn read(&self) -> u32 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn reset(&self) {
+        self.0.store(0, Ordering::Relaxed);
+    }
+}
+
+pub(crate) struct Counters {
+    pub(crate) mul: Counter,
+    pub(crate) square: Counter,
+    pub(crate) add_with_correction: Counter,
+    pub(crate) reduction: Counter,
+}
+
+impl Counters {
+    const fn new() -> Counters {
+        Counters {
+            mul: Counter::new(),
+            square: Counter::new(),
+            add_with_correction: Counter::new(),
+            reduction: Counter::new(),
+        }
+    }
+
+    fn read(&self) -> OpCounts {
+        OpCounts {
+            mul: self.mul.read(),
+            square: self.square.read(),
+            add_with_correction: self.add_with_correction.read(),
+            reduction: self.reduction.read(),
+        }
+    }
+
+    fn reset(&self) {
+        self.mul.reset();
+        self.square.reset();
+        self.add_with_correction.reset();
+        self.reduction.reset();
+    }
+}
+
+pub(crate) static FIELD: Counters = Counters::new();
+
+pub(crate) static SCALAR: Counters = Counters::new();
@@ -0,0 +1,383 @@
+//! Differential tests of the R0 backend against the `u64` serial backend.
+//!
+//! Both backends are compiled into the same test binary when the R0 backend is built with
+//! `curve25519_dalek_backend = "risc0-emulated"`. Every operation is run on the same edge-case
+//! and random inputs through `FieldElementR0`/`ScalarR0` and `FieldElement51`/`Scalar52`, and
+//! the canonical encodings of the results are required to agree bit for bit.
+//!
+//! The two scalar implementations use different Montgomery moduli (\\(2\^{261}\\) for R0 and
+//! \\(2\^{260}\\) for `u64`), so Montgomery-form values are compared after accounting for the
+//! extra factor of two.
+
+use crypto_bigint::{Encoding, U256};
+use rand::RngCore;
+use std::vec::Vec;
+
+use super::constants as r0_constants;
+use super::field::FieldElementR0;
+use super::scalar::ScalarR0;
+use crate::backend::serial::u64::constants as u64_constants;
+use crate::backend::serial::u64::field::FieldElement51;
+use crate::backend::serial::u64::scalar::Scalar52;
+
+/// Number of random inputs to run through each operation.
+const ITERATIONS: usize = 1000;
+
+/// \\(p = 2\^{255} - 19\\)
+const P: U256 =
+    U256::from_be_hex("7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFED");
+
+/// \\(\ell = 2\^{252} + 27742317777372353535851937790883648493\\)
+const L: U256 =
+    U256::from_be_hex("1000000000000000000000000000000014DEF9DEA2F79CD65812631A5CF5D3ED");
+
+/// Add a small signed offset to a little-endian 256-bit integer, wrapping mod \\(2\^{256}\\).
+fn offset(bytes: &[u8; 32], delta: i64) -> [u8; 32] {
+    let x = U256::from_le_bytes(*bytes);
+    let d = U256::from_u64(delta.unsigned_abs());
+    let y = if delta < 0 {
+        x.wrapping_sub(&d)
+    } else {
+        x.wrapping_add(&d)
+    };
+    y.to_le_bytes()
+}
+
+fn random_bytes<const N: usize>() -> [u8; N] {
+    let mut bytes = [0u8; N];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes
+}
+
+/// Field element encodings that exercise the reduction in `from_bytes`: small values, values
+/// around \\(p\\), the non-canonical encodings \\(p, \ldots, 2\^{255} - 1\\), and encodings
+/// with the (ignored) high bit set.
+fn field_edge_cases() -> Vec<[u8; 32]> {
+    let mut cases = vec![[0u8; 32], offset(&[0u8; 32], 1), offset(&[0u8; 32], 2)];
+    for delta in -2..19 {
+        cases.push(offset(&P.to_le_bytes(), delta));
+    }
+    for case in cases.clone() {
+        let mut high = case;
+        high[31] |= 0x80;
+        cases.push(high);
+    }
+    cases
+}
+
+/// Scalar encodings around zero, \\(\ell\\) and \\(2\^{256}\\).
+fn scalar_edge_cases() -> Vec<[u8; 32]> {
+    let mut cases = vec![[0u8; 32], offset(&[0u8; 32], 1), [0xFF; 32]];
+    for delta in -2..3 {
+        cases.push(offset(&L.to_le_bytes(), delta));
+    }
+    cases.push(L.wrapping_add(&L).to_le_bytes());
+    cases.push(offset(&[0xFF; 32], -1));
+    cases
+}
+
+/// Inputs for field operations: the edge cases followed by random encodings.
+fn field_inputs() -> Vec<[u8; 32]> {
+    let mut inputs = field_edge_cases();
+    inputs.extend((0..ITERATIONS).map(|_| random_bytes::<32>()));
+    inputs
+}
+
+/// Inputs for scalar operations: the edge cases followed by random encodings.
+fn scalar_inputs() -> Vec<[u8; 32]> {
+    let mut inputs = scalar_edge_cases();
+    inputs.extend((0..ITERATIONS).map(|_| random_bytes::<32>()));
+    inputs
+}
+
+fn fe_pair(bytes: &[u8; 32]) -> (FieldElementR0, FieldElement51) {
+    (
+        FieldElementR0::from_bytes(bytes),
+        FieldElement51::from_bytes(bytes),
+    )
+}
+
+/// Load a scalar into both backends and reduce it mod \\(\ell\\).
+///
+/// `Scalar52` has no standalone reduction, so it is reduced by multiplying by one, which is
+/// valid for any 256-bit input.
+fn reduced_scalar_pair(bytes: &[u8; 32]) -> (ScalarR0, Scalar52) {
+    let one = Scalar52([1, 0, 0, 0, 0]);
+    (
+        ScalarR0::reduce(&ScalarR0::from_bytes(bytes)),
+        Scalar52::mul(&Scalar52::from_bytes(bytes), &one),
+    )
+}
+
+#[track_caller]
+fn assert_fe_eq(r0: &FieldElementR0, reference: &FieldElement51, op: &str, input: &[u8; 32]) {
+    assert_eq!(
+        r0.as_bytes(),
+        reference.as_bytes(),
+        "{} disagrees on input {:02x?}",
+        op,
+        input
+    );
+}
+
+#[track_caller]
+fn assert_scalar_eq(r0: &ScalarR0, reference: &Scalar52, op: &str, input: &[u8]) {
+    assert_eq!(
+        r0.as_bytes(),
+        reference.as_bytes(),
+        "{} disagrees on input {:02x?}",
+        op,
+        input
+    );
+}
+
+#[test]
+fn field_constants() {
+    let pairs = [
+        (r0_constants::MINUS_ONE, u64_constants::MINUS_ONE),
+        (r0_constants::EDWARDS_D, u64_constants::EDWARDS_D),
+        (r0_constants::EDWARDS_D2, u64_constants::EDWARDS_D2),
+        (
+            r0_constants::ONE_MINUS_EDWARDS_D_SQUARED,
+            u64_constants::ONE_MINUS_EDWARDS_D_SQUARED,
+        ),
+        (
+            r0_constants::EDWARDS_D_MINUS_ONE_SQUARED,
+            u64_constants::EDWARDS_D_MINUS_ONE_SQUARED,
+        ),
+        (
+            r0_constants::SQRT_AD_MINUS_ONE,
+            u64_constants::SQRT_AD_MINUS_ONE,
+        ),
+        (
+            r0_constants::INVSQRT_A_MINUS_D,
+            u64_constants::INVSQRT_A_MINUS_D,
+        ),
+        (r0_constants::SQRT_M1, u64_constants::SQRT_M1),
+        (r0_constants::SQRT_ID, u64_constants::SQRT_ID),
+        (
+            r0_constants::APLUS2_OVER_FOUR,
+            u64_constants::APLUS2_OVER_FOUR,
+        ),
+        (r0_constants::MONTGOMERY_A, u64_constants::MONTGOMERY_A),
+        (
+            r0_constants::MONTGOMERY_A_NEG,
+            u64_constants::MONTGOMERY_A_NEG,
+        ),
+    ];
+    for (i, (r0, reference)) in pairs.iter().enumerate() {
+        assert_eq!(r0.as_bytes(), reference.as_bytes(), "constant #{}", i);
+    }
+    assert_eq!(
+        r0_constants::ED25519_SQRTAM2.as_bytes(),
+        u64_constants::ED25519_SQRTAM2.as_bytes()
+    );
+    #[cfg(all(feature = "group", feature = "hazmat"))]
+    for (r0, reference) in [
+        (r0_constants::FIELD_TWO_INV, u64_constants::FIELD_TWO_INV),
+        (
+            r0_constants::FIELD_MULTIPLICATIVE_GENERATOR,
+            u64_constants::FIELD_MULTIPLICATIVE_GENERATOR,
+        ),
+        (
+            r0_constants::FIELD_ROOT_OF_UNITY_INV,
+            u64_constants::FIELD_ROOT_OF_UNITY_INV,
+        ),
+        (r0_constants::FIELD_DELTA, u64_constants::FIELD_DELTA),
+    ] {
+        assert_eq!(r0.as_bytes(), reference.as_bytes());
+    }
+}
+
+#[test]
+fn scalar_constants() {
+    assert_eq!(r0_constants::L.0.to_le_bytes(), u64_constants::L.as_bytes());
+    // The Montgomery moduli differ by a factor of two.
+    let u64_r_doubled = Scalar52::add(&u64_constants::R, &u64_constants::R);
+    assert_scalar_eq(&r0_constants::R, &u64_r_doubled, "R", &[]);
+}
+
+#[test]
+fn field_from_bytes() {
+    for input in field_inputs() {
+        let (r0, reference) = fe_pair(&input);
+        assert_fe_eq(&r0, &reference, "from_bytes", &input);
+    }
+}
+
+#[test]
+fn field_unary_ops() {
+    for input in field_inputs() {
+        let (r0, reference) = fe_pair(&input);
+
+        assert_fe_eq(&-&r0, &-&reference, "neg", &input);
+        assert_fe_eq(&r0.square(), &reference.square(), "square", &input);
+        assert_fe_eq(&r0.square2(), &reference.square2(), "square2", &input);
+        for k in [1, 2, 5, 50] {
+            assert_fe_eq(&r0.pow2k(k), &reference.pow2k(k), "pow2k", &input);
+        }
+    }
+}
+
+#[test]
+fn field_binary_ops() {
+    let inputs = field_inputs();
+    let edges = field_edge_cases();
+    // Pair every edge case with every other, then random inputs with their neighbours.
+    let pairs = edges
+        .iter()
+        .flat_map(|a| edges.iter().map(move |b| (*a, *b)))
+        .chain(inputs.windows(2).map(|w| (w[0], w[1])));
+
+    for (a, b) in pairs {
+        let (a_r0, a_ref) = fe_pair(&a);
+        let (b_r0, b_ref) = fe_pair(&b);
+
+        assert_fe_eq(&(&a_r0 + &b_r0), &(&a_ref + &b_ref), "add", &a);
+        assert_fe_eq(&(&a_r0 - &b_r0), &(&a_ref - &b_ref), "sub", &a);
+        assert_fe_eq(&(&a_r0 * &b_r0), &(&a_ref * &b_ref), "mul", &a);
+    }
+}
+
+#[test]
+fn scalar_reduce() {
+    for input in scalar_inputs() {
+        let (r0, reference) = reduced_scalar_pair(&input);
+        assert_scalar_eq(&r0, &reference, "reduce", &input);
+        // `ScalarR0::as_bytes` reduces on its own, so it must agree without the explicit
+        // reduction as well.
+        assert_scalar_eq(
+            &ScalarR0::from_bytes(&input),
+            &reference,
+            "as_bytes",
+            &input,
+        );
+    }
+}
+
+#[test]
+fn scalar_from_bytes_wide() {
+    let mut inputs: Vec<[u8; 64]> = Vec::new();
+    for lo in scalar_edge_cases() {
+        for hi in scalar_edge_cases() {
+            let mut wide = [0u8; 64];
+            wide[..32].copy_from_slice(&lo);
+            wide[32..].copy_from_slice(&hi);
+            inputs.push(wide);
+        }
+    }
+    inputs.extend((0..ITERATIONS).map(|_| random_bytes::<64>()));
+
+    for input in inputs {
+        let r0 = ScalarR0::from_bytes_wide(&input);
+        let reference = Scalar52::from_bytes_wide(&input);
+        assert_scalar_eq(&r0, &reference, "from_bytes_wide", &input);
+    }
+}
+
+#[test]
+fn scalar_mul() {
+    let inputs = scalar_inputs();
+    let edges = scalar_edge_cases();
+    let pairs = edges
+        .iter()
+        .flat_map(|a| edges.iter().map(move |b| (*a, *b)))
+        .chain(inputs.windows(2).map(|w| (w[0], w[1])));
+
+    // Both implementations accept unreduced 256-bit inputs to `mul` and `square`.
+    for (a, b) in pairs {
+        let a_r0 = ScalarR0::from_bytes(&a);
+        let b_r0 = ScalarR0::from_bytes(&b);
+        let a_ref = Scalar52::from_bytes(&a);
+        let b_ref = Scalar52::from_bytes(&b);
+
+        assert_scalar_eq(
+            &ScalarR0::mul(&a_r0, &b_r0),
+            &Scalar52::mul(&a_ref, &b_ref),
+            "mul",
+            &a,
+        );
+        assert_scalar_eq(&a_r0.square(), &a_ref.square(), "square", &a);
+    }
+}
+
+#[test]
+fn scalar_add_sub_negate() {
+    let inputs = scalar_inputs();
+    let edges = scalar_edge_cases();
+    let pairs = edges
+        .iter()
+        .flat_map(|a| edges.iter().map(move |b| (*a, *b)))
+        .chain(inputs.windows(2).map(|w| (w[0], w[1])));
+
+    // Addition and subtraction are only defined on reduced inputs.
+    for (a, b) in pairs {
+        let (a_r0, a_ref) = reduced_scalar_pair(&a);
+        let (b_r0, b_ref) = reduced_scalar_pair(&b);
+
+        assert_scalar_eq(
+            &ScalarR0::add(&a_r0, &b_r0),
+            &Scalar52::add(&a_ref, &b_ref),
+            "add",
+            &a,
+        );
+        assert_scalar_eq(
+            &ScalarR0::sub(&a_r0, &b_r0),
+            &Scalar52::sub(&a_ref, &b_ref),
+            "sub",
+            &a,
+        );
+        assert_scalar_eq(
+            &ScalarR0::negate(&a_r0),
+            &Scalar52::sub(&Scalar52::ZERO, &a_ref),
+            "negate",
+            &a,
+        );
+    }
+}
+
+#[test]
+fn scalar_montgomery() {
+    let inputs = scalar_inputs();
+    let edges = scalar_edge_cases();
+    let pairs = edges
+        .iter()
+        .flat_map(|a| edges.iter().map(move |b| (*a, *b)))
+        .chain(inputs.windows(2).map(|w| (w[0], w[1])));
+
+    for (a, b) in pairs {
+        let (a_r0, a_ref) = reduced_scalar_pair(&a);
+        let (b_r0, b_ref) = reduced_scalar_pair(&b);
+
+        // R0 computes a*b/2^261 and u64 computes a*b/2^260, so doubling the R0 result must
+        // reproduce the u64 one exactly.
+        let mont_r0 = ScalarR0::montgomery_mul(&a_r0, &b_r0);
+        let mont_ref = Scalar52::montgomery_mul(&a_ref, &b_ref);
+        assert_scalar_eq(
+            &ScalarR0::add(&mont_r0, &mont_r0),
+            &mont_ref,
+            "montgomery_mul",
+            &a,
+        );
+        let sq_r0 = a_r0.montgomery_square();
+        assert_scalar_eq(
+            &ScalarR0::add(&sq_r0, &sq_r0),
+            &a_ref.montgomery_square(),
+            "montgomery_square",
+            &a,
+        );
+
+        // Round trips through each backend's own Montgomery domain must agree.
+        let product_r0 = ScalarR0::montgomery_mul(&a_r0.as_montgomery(), &b_r0.as_montgomery())
+            .from_montgomery();
+        let product_ref = Scalar52::montgomery_mul(&a_ref.as_montgomery(), &b_ref.as_montgomery())
+            .from_montgomery();
+        assert_scalar_eq(&product_r0, &product_ref, "montgomery round trip", &a);
+        assert_scalar_eq(
+            &a_r0.as_montgomery().from_montgomery(),
+            &a_ref,
+            "as_montgomery",
+            &a,
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Software emulation of the RISC Zero bigint precompile.
+//!
+//! This mirrors the `modmul_u256_denormalized` function exported by the `risc0` module of RISC
+//! Zero's `crypto-bigint` fork, which is the only precompile function the R0 backend calls, so
+//! that the R0 backend can be built and tested on ordinary hosts by setting
+//! `curve25519_dalek_backend = "risc0-emulated"`.
+//!
+//! Inside the zkvm, the result of a bigint multiplication is supplied by the host and the
+//! circuit only checks that it is congruent to `a * b`. The emulation below behaves like a
+//! cooperative host, always returning the fully reduced value. Like the host, it is **not**
+//! constant time, and it should only be used for testing.
+
+use crypto_bigint::U256;
+
+/// Compute `a * b (mod modulus)`.
+///
+/// A modulus of zero is interpreted as \\(2\^{256}\\), matching the precompile.
+pub(crate) fn modmul_u256_denormalized(a: &U256, b: &U256, modulus: &U256) -> U256 {
+    let (lo, hi) = a.mul_wide(b);
+    if *modulus == U256::ZERO {
+        return lo;
+    }
+
+    let (remainder, _) = U256::const_rem_wide((lo, hi), modulus);
+    remainder
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// p = 2^255 - 19
+    const P: U256 =
+        U256::from_be_hex("7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFED");
+
+    /// l = 2^252 + 27742317777372353535851937790883648493
+    const L: U256 =
+        U256::from_be_hex("1000000000000000000000000000000014DEF9DEA2F79CD65812631A5CF5D3ED");
+
+    #[test]
+    fn modmul_reduces() {
+        // (p - 1)^2 = 1 (mod p)
+        let p_minus_one = P.wrapping_sub(&U256::ONE);
+        assert_eq!(
+            modmul_u256_denormalized(&p_minus_one, &p_minus_one, &P),
+            U256::ONE
+        );
+
+        // 2^255 = 19 (mod p)
+        let two_pow_128 = U256::ONE.shl_vartime(128);
+        let two_pow_127 = U256::ONE.shl_vartime(127);
+        assert_eq!(
+            modmul_u256_denormalized(&two_pow_128, &two_pow_127, &P),
+            U256::from_u32(19)
+        );
+    }
+
+    #[test]
+    fn modmul_reduces_unreduced_inputs() {
+        // (2^256 - 1) * 1 = 37 (mod p)
+        assert_eq!(
+            modmul_u256_denormalized(&U256::MAX, &U256::ONE, &P),
+            U256::from_u32(37)
+        );
+    }
+
+    #[test]
+    fn modmul_matches_double_and_add() {
+        let a =
+            U256::from_be_hex("0D96018BB8255FFFCC11FAD13433D2BAF0672BBF9D75E1ECDACB75071E1458FA");
+        let moduli = [
+            P,
+            L,
+            U256::MAX,
+            U256::from_u64(0xFFFF_FFFF_0000_0001),
+            U256::from_u32(7),
+        ];
+        let mut x = a;
+        for _ in 0..64 {
+            for m in moduli.iter() {
+                // x * a (mod m) by double-and-add over the bits of a
+                let (x_mod_m, _) = x.const_rem(m);
+                let mut expected = U256::ZERO;
+                for i in (0..256).rev() {
+                    expected = expected.add_mod(&expected, m);
+                    if a.bit_vartime(i) {
+                        expected = expected.add_mod(&x_mod_m, m);
+                    }
+                }
+                assert_eq!(modmul_u256_denormalized(&x, &a, m), expected);
+            }
+            x = x.wrapping_mul(&x).wrapping_add(&a);
+        }
+    }
+
+    #[test]
+    fn modmul_zero_modulus_wraps() {
+        let two_pow_255 = U256::ONE.shl_vartime(255);
+        assert_eq!(
+            modmul_u256_denormalized(&two_pow_255, &U256::from_u32(2), &U256::ZERO),
+            U256::ZERO
+        );
+    }
+}
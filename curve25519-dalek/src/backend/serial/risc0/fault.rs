@@ -0,0 +1,52 @@
+//! A sticky flag recording that the host did not cooperate with the R0 backend.
+//!
+//! Values returned by the host, such as the unreduced results of the bigint precompile, are only
+//! guaranteed to be in range if the host is cooperative. Rather than panicking deep inside point
+//! arithmetic when they are not, the backend records the fault here and continues with a
+//! meaningless but well-formed value. Once set, the flag stays set, so any result computed after
+//! the fault can be rejected through a checked API such as
+//! [`EdwardsPoint::try_compress`](crate::EdwardsPoint::try_compress):
+//!
+//! ```ignore
+//! use curve25519_dalek::risc0_fault;
+//!
+//! let point = a * b;
+//! if risc0_fault::host_misbehaved() {
+//!     // Reject the proof instead of committing to `point`.
+//! }
+//! ```
+//!
+//! The fault is recorded by:
+//!
+//! - field additions whose operands are too far out of range for the sum to be corrected, and
+//!   encodings of unreduced field elements;
+//! - scalar multiplications and reductions whose result is not reduced;
+//! - answers from a [`risc0_hint`](crate::risc0_hint) handler which fail their check.
+//!
+//! Field multiplications are not checked, since their results are allowed to be unreduced.
+//!
+//! Only [`EdwardsPoint::try_compress`](crate::EdwardsPoint::try_compress) and
+//! [`EdwardsPoint::eq_compressed`](crate::EdwardsPoint::eq_compressed) consult the flag, and
+//! through the latter, signature verification in `ed25519-dalek`. Every other operation,
+//! including `EdwardsPoint::compress` and all Ristretto and Montgomery operations, returns its
+//! result regardless, so a guest which commits to such a result must check [`host_misbehaved`]
+//! itself.
+//!
+//! The flag is global. It is only ever set, so plain loads and stores are enough to update it
+//! from several threads.
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+static HOST_MISBEHAVED: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether the host has been caught misbehaving by any field or scalar operation so far.
+///
+/// When this is `true`, every value computed by the R0 backend must be treated as untrusted.
+pub fn host_misbehaved() -> bool {
+    HOST_MISBEHAVED.load(Ordering::Relaxed)
+}
+
+/// Record that the host returned a value which a cooperative host would not have.
+pub(crate) fn record() {
+    HOST_MISBEHAVED.store(true, Ordering::Relaxed);
+}
@@ -7,12 +7,15 @@ use core::ops::{Add, AddAssign};
 use core::ops::{Mul, MulAssign};
 use core::ops::{Sub, SubAssign};
 
-use crypto_bigint::{risc0, Encoding, U256};
-use subtle::{Choice, ConditionallySelectable, ConstantTimeLess};
+use crypto_bigint::{Encoding, Limb, U256};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess};
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
+use super::{constants, fault, precompile};
+use crate::risc0_hint::{self, Query};
+
 /// A `FieldElementR0` represents an element of the field
 /// \\( \mathbb Z / (2\^{255} - 19)\\). `FieldElementR0`
 /// leverages RISC Zero's big integer accelerated zkvm circuit.
@@ -20,7 +23,7 @@ use zeroize::Zeroize;
 /// # Note
 ///
 /// The `curve25519_dalek::field` module provides a type alias
-/// `curve25519_dalek::field::FieldElement` to either `FieldElement51`,
+/// `curve25519_dalek::field::BackendFieldElement` to either `FieldElement51`,
 /// `FieldElement2625` or `FieldElementR0`.
 ///
 /// The backend-specific type `FieldElementR0` should not be used
@@ -57,47 +60,67 @@ impl Zeroize for FieldElementR0 {
 
 impl<'b> AddAssign<&'b FieldElementR0> for FieldElementR0 {
     fn add_assign(&mut self, rhs: &'b FieldElementR0) {
-        let self_limbs = self.0.as_limbs();
-        let rhs_limbs = rhs.0.as_limbs();
-        let correction_limbs = MODULUS_CORRECTION.as_limbs();
-
-        // Carrying addition of self and rhs, with the overflow correction added in.
-        // Correction is added to carries with wrapping_add since they cannot overflow.
-        let (a0, carry0) = self_limbs[0].adc(rhs_limbs[0], correction_limbs[0]);
-        let (a1, carry1) =
-            self_limbs[1].adc(rhs_limbs[1], carry0.wrapping_add(correction_limbs[1]));
-        let (a2, carry2) =
-            self_limbs[2].adc(rhs_limbs[2], carry1.wrapping_add(correction_limbs[2]));
-        let (a3, carry3) =
-            self_limbs[3].adc(rhs_limbs[3], carry2.wrapping_add(correction_limbs[3]));
-        let (a4, carry4) =
-            self_limbs[4].adc(rhs_limbs[4], carry3.wrapping_add(correction_limbs[4]));
-        let (a5, carry5) =
-            self_limbs[5].adc(rhs_limbs[5], carry4.wrapping_add(correction_limbs[5]));
-        let (a6, carry6) =
-            self_limbs[6].adc(rhs_limbs[6], carry5.wrapping_add(correction_limbs[6]));
-        let (a7, carry7) =
-            self_limbs[7].adc(rhs_limbs[7], carry6.wrapping_add(correction_limbs[7]));
-        self.0 = U256::from([a0, a1, a2, a3, a4, a5, a6, a7]);
-
-        // If the inputs are not in the range [0, p), then then carry7 may be greater than 1,
-        // indicating more than one overflow occurred. In this case, the code below will not
-        // correct the value. If the host is cooperative, this should never happen.
-        assert!(carry7.0 <= 1);
-
-        // If a carry occured, then the correction was already added and the result is correct.
-        // If a carry did not occur, the correction needs to be removed. Result will be in [0, p).
-        // Wrap and unwrap to prevent the compiler interpreting this as a boolean, potentially
-        // introducing non-constant time code.
-        let mask = 1 - Choice::from(carry7.0 as u8).unwrap_u8();
-        let c0 = MODULUS_CORRECTION.as_words()[0] * (mask as u32);
-        let c7 = MODULUS_CORRECTION.as_words()[7] * (mask as u32);
-        let correction = U256::from_words([c0, 0, 0, 0, 0, 0, 0, c7]);
-
-        // The correction value was either already added to a, or is 0, so this sub will not
-        // underflow.
-        self.0 = self.0.wrapping_sub(&correction);
+        count_op!(FIELD.add_with_correction);
+        let (sum, in_range) = add_with_correction(&self.0, &rhs.0);
+        if !in_range {
+            fault::record();
+        }
+        self.0 = sum;
+    }
+}
+
+/// Compute `a + b (mod p)` for `a` and `b` in the range `[0, p)`, returning `false` if they were
+/// so far out of range that the sum could not be corrected.
+///
+/// Results of the precompile from a cooperative host are always in range. Otherwise the sum is
+/// meaningless, and the caller should record the fault.
+#[allow(clippy::needless_range_loop)]
+fn add_with_correction(a: &U256, b: &U256) -> (U256, bool) {
+    let a_limbs = a.as_limbs();
+    let b_limbs = b.as_limbs();
+    let correction_limbs = MODULUS_CORRECTION.as_limbs();
+
+    // Carrying addition of a and b, with the overflow correction added in.
+    // Correction is added to carries with wrapping_add since they cannot overflow.
+    // The limb count depends on the word size, which is 32 bits inside the zkvm but may be
+    // 64 bits when the precompile is emulated on the host.
+    let mut limbs = [Limb::ZERO; U256::LIMBS];
+    let mut carry = Limb::ZERO;
+    for i in 0..U256::LIMBS {
+        let (limb, limb_carry) =
+            a_limbs[i].adc(b_limbs[i], carry.wrapping_add(correction_limbs[i]));
+        limbs[i] = limb;
+        carry = limb_carry;
+    }
+    let sum = U256::from(limbs);
+
+    // If the inputs are not in the range [0, p), then then carry may be greater than 1,
+    // indicating more than one overflow occurred. In this case, the code below will not
+    // correct the value. If the host is cooperative, this should never happen.
+    let in_range = carry.0 <= 1;
+
+    // If a carry occured, then the correction was already added and the result is correct.
+    // If a carry did not occur, the correction needs to be removed. Result will be in [0, p).
+    let carried = carry.0.ct_eq(&1);
+    let correction = U256::conditional_select(&MODULUS_CORRECTION, &U256::ZERO, carried);
+
+    // The correction value was either already added to a, or is 0, so this sub will not
+    // underflow.
+    (sum.wrapping_sub(&correction), in_range)
+}
+
+/// Reduce `value` into the range `[0, p)`, returning whether it already was.
+///
+/// The results of the precompile from a cooperative host always are. Since 2^256 < 3p, at most
+/// two subtractions are needed otherwise.
+fn normalize(value: &U256) -> (U256, bool) {
+    let in_range = bool::from(value.ct_lt(&P));
+    let mut value = *value;
+    for _ in 0..2 {
+        let reduced = value.wrapping_sub(&P);
+        value = U256::conditional_select(&reduced, &value, value.ct_lt(&P));
     }
+    (value, in_range)
 }
 
 impl<'a, 'b> Add<&'b FieldElementR0> for &'a FieldElementR0 {
@@ -126,7 +149,8 @@ impl<'a, 'b> Sub<&'b FieldElementR0> for &'a FieldElementR0 {
 
 impl<'b> MulAssign<&'b FieldElementR0> for FieldElementR0 {
     fn mul_assign(&mut self, _rhs: &'b FieldElementR0) {
-        let result = risc0::modmul_u256_denormalized(&self.0, &_rhs.0, &P);
+        count_op!(FIELD.mul);
+        let result = precompile::modmul_u256_denormalized(&self.0, &_rhs.0, &P);
         self.0 = result;
     }
 }
@@ -175,7 +199,8 @@ impl FieldElementR0 {
 
     /// Invert the sign of this field element
     pub fn negate(&mut self) {
-        let result = risc0::modmul_u256_denormalized(&self.0, &Self::MINUS_ONE.0, &P);
+        count_op!(FIELD.mul);
+        let result = precompile::modmul_u256_denormalized(&self.0, &Self::MINUS_ONE.0, &P);
         self.0 = result;
     }
 
@@ -192,12 +217,12 @@ impl FieldElementR0 {
     /// Load a `FieldElementR0` from the low 255 bits of a 256-bit
     /// input.
     pub fn from_bytes(data: &[u8; 32]) -> FieldElementR0 {
-        let mut val: U256 = U256::from_le_bytes(*data);
-        let val_words = val.as_words_mut();
-        val_words[7] = val_words[7] & 0x7FFFFFFF;
-        let val = U256::from_words(*val_words);
+        let mut data = *data;
+        data[31] &= 0x7F;
+        let val: U256 = U256::from_le_bytes(data);
         // Use a modular multiplication by one to reduce the value to [0, p).
-        let val = risc0::modmul_u256_denormalized(&val, &FieldElementR0::ONE.0, &P);
+        count_op!(FIELD.reduction);
+        let val = precompile::modmul_u256_denormalized(&val, &FieldElementR0::ONE.0, &P);
         FieldElementR0(val)
     }
 
@@ -206,21 +231,110 @@ impl FieldElementR0 {
     #[allow(clippy::identity_op)]
     pub fn as_bytes(&self) -> [u8; 32] {
         // Check that the output is normalized. This will always be the case if the host is
-        // cooperative.
-        assert!(self.0.ct_lt(&P).unwrap_u8() == 1);
-        self.0.to_le_bytes()
+        // cooperative. Otherwise the fault is recorded, and the value is reduced anyway so that
+        // the encoding stays canonical.
+        let (value, in_range) = normalize(&self.0);
+        if !in_range {
+            fault::record();
+        }
+        value.to_le_bytes()
     }
 
     /// Compute `self^2`.
     pub fn square(&self) -> FieldElementR0 {
-        let result = risc0::modmul_u256_denormalized(&self.0, &self.0, &P);
+        count_op!(FIELD.square);
+        let result = precompile::modmul_u256_denormalized(&self.0, &self.0, &P);
         FieldElementR0(result)
     }
 
     /// Compute `2*self^2`.
     pub fn square2(&self) -> FieldElementR0 {
         let squared = self.square();
-        let result = risc0::modmul_u256_denormalized(&Self::TWO.0, &squared.0, &P);
-        FieldElementR0(result)
+        &squared + &squared
+    }
+
+    /// Read a field element answered by the host, which must be canonical.
+    fn from_answer(answer: &[u8; 32]) -> Option<FieldElementR0> {
+        let value = U256::from_le_bytes(*answer);
+        if bool::from(value.ct_lt(&P)) {
+            Some(FieldElementR0(value))
+        } else {
+            None
+        }
+    }
+
+    /// Ask the host for the inverse of `self`, and check it with one multiplication.
+    ///
+    /// Returns `None` if there is no host to ask, or if its answer is wrong, which is recorded as
+    /// a fault. As with `invert`, the inverse of zero is zero.
+    pub(crate) fn invert_hinted(&self) -> Option<FieldElementR0> {
+        let answer = risc0_hint::ask(&Query::FieldInvert(self.as_bytes()))?;
+        let inverse = FieldElementR0::from_answer(&answer).filter(|inverse| {
+            let is_one = (self * inverse).ct_eq(&FieldElementR0::ONE);
+            let both_zero =
+                self.ct_eq(&FieldElementR0::ZERO) & inverse.ct_eq(&FieldElementR0::ZERO);
+            (is_one | both_zero).into()
+        });
+        if inverse.is_none() {
+            fault::record();
+        }
+        inverse
+    }
+
+    /// Ask the host for the result of `sqrt_ratio_i(u, v)`, and check it with three
+    /// multiplications.
+    ///
+    /// The root `r` must be nonnegative, and satisfies `v*r^2 = u` if `u/v` is square, or
+    /// `v*r^2 = i*u` if it is not. Both roots are unique, which makes the result identical to the
+    /// one computed with `pow_p58`. If `v` is zero, `r` must be zero.
+    ///
+    /// Returns `None` if there is no host to ask, or if its answer is wrong, which is recorded as
+    /// a fault.
+    pub(crate) fn sqrt_ratio_i_hinted(
+        u: &FieldElementR0,
+        v: &FieldElementR0,
+    ) -> Option<(Choice, FieldElementR0)> {
+        let answer = risc0_hint::ask(&Query::FieldSqrtRatioI(u.as_bytes(), v.as_bytes()))?;
+        let result = FieldElementR0::from_answer(&answer).and_then(|r| {
+            let check = v * &r.square();
+            let i_u = &constants::SQRT_M1 * u;
+            let was_square = check.ct_eq(u);
+            let was_nonsquare = check.ct_eq(&i_u);
+
+            let v_is_zero = v.is_zero();
+            let is_root = (!v_is_zero & (was_square | was_nonsquare)) | (v_is_zero & r.is_zero());
+            if bool::from(is_root & !r.is_negative()) {
+                Some((was_square, r))
+            } else {
+                None
+            }
+        });
+        if result.is_none() {
+            fault::record();
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Values out of range, which a cooperative host never returns, are detected.
+    #[test]
+    fn out_of_range_values() {
+        let p_minus_one = P.wrapping_sub(&U256::ONE);
+        assert_eq!(
+            add_with_correction(&p_minus_one, &U256::ONE),
+            (U256::ZERO, true)
+        );
+
+        // Adding two values near 2^256 overflows twice.
+        assert!(!add_with_correction(&U256::MAX, &U256::MAX).1);
+
+        // Unreduced values are still reduced, so that encodings stay canonical.
+        assert_eq!(normalize(&p_minus_one), (p_minus_one, true));
+        assert_eq!(normalize(&P), (U256::ZERO, false));
+        assert_eq!(normalize(&U256::MAX), (U256::from_u8(37), false));
     }
 }
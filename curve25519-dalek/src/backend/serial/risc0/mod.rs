@@ -1,5 +1,35 @@
+/// Record an operation in the `risc0-op-counters` counters, e.g. `count_op!(FIELD.mul)`.
+///
+/// Expands to nothing unless the `risc0-op-counters` feature is enabled.
+macro_rules! count_op {
+    ($counters:ident . $op:ident) => {
+        #[cfg(feature = "risc0-op-counters")]
+        $crate::backend::serial::risc0::counters::$counters
+            .$op
+            .increment();
+    };
+}
+
 pub mod field;
 
 pub mod scalar;
 
 pub mod constants;
+
+pub mod fault;
+
+#[cfg(feature = "risc0-op-counters")]
+pub mod counters;
+
+#[cfg(curve25519_dalek_backend = "risc0-emulated")]
+pub(crate) mod emulated;
+
+// The RISC Zero bigint precompile, or its software emulation when the R0 backend is built for a
+// host with `curve25519_dalek_backend = "risc0-emulated"`.
+#[cfg(not(curve25519_dalek_backend = "risc0-emulated"))]
+pub(crate) use crypto_bigint::risc0 as precompile;
+#[cfg(curve25519_dalek_backend = "risc0-emulated")]
+pub(crate) use emulated as precompile;
+
+#[cfg(all(test, curve25519_dalek_backend = "risc0-emulated"))]
+mod differential;
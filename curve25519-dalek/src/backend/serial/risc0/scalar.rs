@@ -2,12 +2,15 @@
 //! with RISC0 Acceleration
 
 use core::fmt::Debug;
-use crypto_bigint::{risc0, Encoding, U256};
+use crypto_bigint::{Encoding, U256};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess};
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
+use super::{fault, precompile};
 use crate::constants;
+use crate::risc0_hint::{self, Query};
 
 /// Multiplicative Inverse of R mod L where R is the Montgomery modulus 2^261
 const R_INVERSE: U256 =
@@ -17,6 +20,31 @@ const R_INVERSE: U256 =
 const TWO_POW_TWO_FIFTY_SIX: U256 =
     U256::from_be_hex("0FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEC6EF5BF4737DCF70D6EC31748D98951D");
 
+/// Compute `a * b (mod l)` with the precompile, checking that the result is in `[0, l)`.
+///
+/// This is always the case if the host is cooperative. Otherwise the fault is recorded, and the
+/// result is reduced anyway so that it stays a well-formed scalar.
+fn modmul(a: &U256, b: &U256) -> U256 {
+    let (result, in_range) =
+        normalize(&precompile::modmul_u256_denormalized(a, b, &constants::L.0));
+    if !in_range {
+        fault::record();
+    }
+    result
+}
+
+/// Reduce `value` into the range `[0, l)`, returning whether it already was.
+///
+/// The results of the precompile from a cooperative host always are, so the reduction, which
+/// takes a long division, is only made for the results of an uncooperative one.
+fn normalize(value: &U256) -> (U256, bool) {
+    if bool::from(value.ct_lt(&constants::L.0)) {
+        (*value, true)
+    } else {
+        (value.const_rem(&constants::L.0).0, false)
+    }
+}
+
 /// The `ScalarR0` struct represents an element in \\(\mathbb{Z} / \ell\mathbb{Z}\\)
 #[derive(Copy, Clone)]
 pub struct ScalarR0(pub U256);
@@ -34,6 +62,12 @@ impl Zeroize for ScalarR0 {
     }
 }
 
+impl ConditionallySelectable for ScalarR0 {
+    fn conditional_select(a: &ScalarR0, b: &ScalarR0, choice: Choice) -> ScalarR0 {
+        ScalarR0(U256::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
 impl ScalarR0 {
     /// The scalar \\( -1 mod L \\).
     pub const MINUS_ONE: ScalarR0 = ScalarR0(U256::from_be_hex(
@@ -58,10 +92,13 @@ impl ScalarR0 {
                 .expect("unable to parse high 32 bytes"),
         );
 
-        let hi_shifted_left_256 = risc0::modmul_u256(&hi, &TWO_POW_TWO_FIFTY_SIX, &constants::L.0);
+        count_op!(SCALAR.mul);
+        let hi_shifted_left_256 = modmul(&hi, &TWO_POW_TWO_FIFTY_SIX);
         // add_mod assumes the lhs + rhs is less than 2p. To guarantee this, we need to mod
         // lo and hi by L
-        let lo = risc0::modmul_u256(&lo, &U256::ONE, &constants::L.0);
+        count_op!(SCALAR.reduction);
+        let lo = modmul(&lo, &U256::ONE);
+        count_op!(SCALAR.add_with_correction);
         let total = hi_shifted_left_256.add_mod(&lo, &constants::L.0);
 
         ScalarR0(total)
@@ -70,78 +107,136 @@ impl ScalarR0 {
     /// Pack the limbs of this `ScalarR0` into 32 bytes.
     #[allow(clippy::identity_op)]
     pub fn as_bytes(&self) -> [u8; 32] {
-        let val = risc0::modmul_u256(&self.0, &U256::ONE, &constants::L.0);
+        count_op!(SCALAR.reduction);
+        let val = modmul(&self.0, &U256::ONE);
         val.to_le_bytes()
     }
 
     /// Compute `a + b` (mod l).
     pub fn add(a: &ScalarR0, b: &ScalarR0) -> ScalarR0 {
+        count_op!(SCALAR.add_with_correction);
         let result = a.0.add_mod(&b.0, &constants::L.0);
         ScalarR0(result)
     }
 
     /// Compute `a - b` (mod l).
     pub fn sub(a: &ScalarR0, b: &ScalarR0) -> ScalarR0 {
+        count_op!(SCALAR.add_with_correction);
         let result = a.0.sub_mod(&b.0, &constants::L.0);
         ScalarR0(result)
     }
 
     /// Compute `-1 * a` (mod l).
     pub fn negate(a: &ScalarR0) -> ScalarR0 {
-        let result = risc0::modmul_u256(&a.0, &Self::MINUS_ONE.0, &constants::L.0);
+        count_op!(SCALAR.mul);
+        let result = modmul(&a.0, &Self::MINUS_ONE.0);
         ScalarR0(result)
     }
 
     /// Compute `a` (mod l).
     pub fn reduce(a: &ScalarR0) -> ScalarR0 {
-        let result = risc0::modmul_u256(&a.0, &U256::ONE, &constants::L.0);
+        count_op!(SCALAR.reduction);
+        let result = modmul(&a.0, &U256::ONE);
         ScalarR0(result)
     }
 
     /// Compute `a * b` (mod l).
     #[inline(never)]
     pub fn mul(a: &ScalarR0, b: &ScalarR0) -> ScalarR0 {
-        let ab = risc0::modmul_u256(&a.0, &b.0, &constants::L.0);
+        count_op!(SCALAR.mul);
+        let ab = modmul(&a.0, &b.0);
         ScalarR0(ab)
     }
 
     /// Compute `a^2` (mod l).
     #[inline(never)]
-    #[allow(dead_code)] // XXX we don't expose square() via the Scalar API
     pub fn square(&self) -> ScalarR0 {
-        let aa = risc0::modmul_u256(&self.0, &self.0, &constants::L.0);
+        count_op!(SCALAR.square);
+        let aa = modmul(&self.0, &self.0);
         ScalarR0(aa)
     }
 
     /// Compute `(a * b) / R` (mod l), where R is the Montgomery modulus 2^261
     #[inline(never)]
     pub fn montgomery_mul(a: &ScalarR0, b: &ScalarR0) -> ScalarR0 {
-        let ab = risc0::modmul_u256_denormalized(&a.0, &b.0, &constants::L.0);
-        let ab_r_inverse = risc0::modmul_u256(&ab, &R_INVERSE, &constants::L.0);
+        count_op!(SCALAR.mul);
+        let ab = precompile::modmul_u256_denormalized(&a.0, &b.0, &constants::L.0);
+        count_op!(SCALAR.reduction);
+        let ab_r_inverse = modmul(&ab, &R_INVERSE);
         ScalarR0(ab_r_inverse)
     }
 
     /// Compute `(a^2) / R` (mod l) in Montgomery form, where R is the Montgomery modulus 2^261
     #[inline(never)]
     pub fn montgomery_square(&self) -> ScalarR0 {
-        let squared = risc0::modmul_u256_denormalized(&self.0, &self.0, &constants::L.0);
-        let squared_r_inverse = risc0::modmul_u256(&squared, &R_INVERSE, &constants::L.0);
+        count_op!(SCALAR.square);
+        let squared = precompile::modmul_u256_denormalized(&self.0, &self.0, &constants::L.0);
+        count_op!(SCALAR.reduction);
+        let squared_r_inverse = modmul(&squared, &R_INVERSE);
         ScalarR0(squared_r_inverse)
     }
 
     /// Puts a ScalarR0 in to Montgomery form, i.e. computes `a*R (mod l)`
     #[inline(never)]
     pub fn as_montgomery(&self) -> ScalarR0 {
-        let result = risc0::modmul_u256(&self.0, &constants::R.0, &constants::L.0);
+        count_op!(SCALAR.mul);
+        let result = modmul(&self.0, &constants::R.0);
         ScalarR0(result)
     }
 
     /// Takes a ScalarR0 out of Montgomery form, i.e. computes `a/R (mod l)`
     #[allow(clippy::wrong_self_convention)]
     pub fn from_montgomery(&self) -> ScalarR0 {
-        let a_r_inverse = risc0::modmul_u256(&self.0, &R_INVERSE, &constants::L.0);
+        count_op!(SCALAR.reduction);
+        let a_r_inverse = modmul(&self.0, &R_INVERSE);
         ScalarR0(a_r_inverse)
     }
+
+    /// Ask the host for the inverse of `self`, and check it with one multiplication.
+    ///
+    /// Returns `None` if there is no host to ask, or if its answer is wrong, which is recorded as
+    /// a fault. As with `invert`, the inverse of zero is zero.
+    pub(crate) fn invert_hinted(&self) -> Option<ScalarR0> {
+        let reduced = ScalarR0::reduce(self);
+        let answer = risc0_hint::ask(&Query::ScalarInvert(reduced.0.to_le_bytes()))?;
+        let inverse = U256::from_le_bytes(answer);
+        let is_canonical = inverse.ct_lt(&constants::L.0);
+        let is_one = ScalarR0::mul(&reduced, &ScalarR0(inverse))
+            .0
+            .ct_eq(&U256::ONE);
+        let both_zero = reduced.0.ct_eq(&U256::ZERO) & inverse.ct_eq(&U256::ZERO);
+        if bool::from(is_canonical & (is_one | both_zero)) {
+            Some(ScalarR0(inverse))
+        } else {
+            fault::record();
+            None
+        }
+    }
+
+    /// Ask the host for the square root of `self`, and check it with one multiplication.
+    ///
+    /// The root `r` must have an even encoding, and satisfies `r^2 = self` if `self` is square,
+    /// or `r^2 = 2*self` if it is not. Both roots are unique, which makes the result identical
+    /// to the one computed in the guest. Returns whether `self` is square, along with `r`.
+    ///
+    /// Returns `None` if there is no host to ask, or if its answer is wrong, which is recorded as
+    /// a fault.
+    pub(crate) fn sqrt_hinted(&self) -> Option<(Choice, ScalarR0)> {
+        let reduced = ScalarR0::reduce(self);
+        let answer = risc0_hint::ask(&Query::ScalarSqrt(reduced.0.to_le_bytes()))?;
+        let root = U256::from_le_bytes(answer);
+        let is_canonical = root.ct_lt(&constants::L.0);
+        let is_even = Choice::from((answer[0] & 1) ^ 1);
+        let check = ScalarR0(root).square();
+        let was_square = check.0.ct_eq(&reduced.0);
+        let was_nonsquare = check.0.ct_eq(&ScalarR0::add(&reduced, &reduced).0);
+        if bool::from(is_canonical & is_even & (was_square | was_nonsquare)) {
+            Some((was_square, ScalarR0(root)))
+        } else {
+            fault::record();
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -259,4 +354,16 @@ mod test {
         let reduced = ScalarR0::from_bytes_wide(&bignum);
         assert!(reduced.0 == C.0);
     }
+
+    /// Values out of range, which a cooperative host never returns, are detected and reduced.
+    #[test]
+    fn out_of_range_values() {
+        let l_minus_one = ScalarR0::MINUS_ONE.0;
+        assert_eq!(normalize(&l_minus_one), (l_minus_one, true));
+        assert_eq!(normalize(&constants::L.0), (U256::ZERO, false));
+        assert_eq!(
+            normalize(&U256::MAX),
+            (TWO_POW_TWO_FIFTY_SIX.wrapping_sub(&U256::ONE), false)
+        );
+    }
 }
@@ -67,6 +67,39 @@ pub(crate) const SQRT_M1: FieldElement2625 = FieldElement2625::from_limbs([
     34513072, 25610706, 9377949, 3500415, 12389472, 33281959, 41962654, 31548777, 326685, 11406482,
 ]);
 
+/// `SQRT_ID` is the nonnegative square root of `i*d`, where `i = sqrt(-1)` and `d` is the Edwards
+/// curve parameter. (This is used internally by the inverse of the Ristretto Elligator map.)
+pub(crate) const SQRT_ID: FieldElement2625 = FieldElement2625::from_limbs([
+    39590824, 701138, 28659366, 23623507, 53932708, 32206357, 36326585, 24309414, 26167230, 1494357,
+]);
+
+/// `FIELD_TWO_INV` is the inverse of 2 in the base field, `(p+1)/2`. (This is used by the `ff`
+/// implementation for `FieldElement`.)
+#[cfg(all(feature = "group", feature = "hazmat"))]
+pub(crate) const FIELD_TWO_INV: FieldElement2625 = FieldElement2625::from_limbs([
+    67108855, 33554431, 67108863, 33554431, 67108863, 33554431, 67108863, 33554431, 67108863,
+    16777215,
+]);
+
+/// `FIELD_MULTIPLICATIVE_GENERATOR` is 2, which generates the multiplicative group of the base
+/// field. (This is used by the `ff` implementation for `FieldElement`.)
+#[cfg(all(feature = "group", feature = "hazmat"))]
+pub(crate) const FIELD_MULTIPLICATIVE_GENERATOR: FieldElement2625 =
+    FieldElement2625::from_limbs([2, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+/// `FIELD_ROOT_OF_UNITY_INV` is `-SQRT_M1`, the inverse of the primitive 4th root of unity
+/// `SQRT_M1 = 2^((p-1)/4)`. (This is used by the `ff` implementation for `FieldElement`.)
+#[cfg(all(feature = "group", feature = "hazmat"))]
+pub(crate) const FIELD_ROOT_OF_UNITY_INV: FieldElement2625 = FieldElement2625::from_limbs([
+    32595773, 7943725, 57730914, 30054016, 54719391, 272472, 25146209, 2005654, 66782178, 22147949,
+]);
+
+/// `FIELD_DELTA` is `2^4 = 16`, the multiplicative generator raised to `2^S`. (This is used by
+/// the `ff` implementation for `FieldElement`.)
+#[cfg(all(feature = "group", feature = "hazmat"))]
+pub(crate) const FIELD_DELTA: FieldElement2625 =
+    FieldElement2625::from_limbs([16, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
 /// `APLUS2_OVER_FOUR` is (A+2)/4. (This is used internally within the Montgomery ladder.)
 pub(crate) const APLUS2_OVER_FOUR: FieldElement2625 =
     FieldElement2625::from_limbs([121666, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
@@ -84,6 +117,13 @@ pub(crate) const MONTGOMERY_A_NEG: FieldElement2625 = FieldElement2625::from_lim
     33554431,
 ]);
 
+/// `ED25519_SQRTAM2` is the nonnegative square root of -(A+2) = -486664, used by the rational
+/// map from Curve25519 to edwards25519.
+pub(crate) const ED25519_SQRTAM2: FieldElement2625 = FieldElement2625::from_limbs([
+    54885894, 25242303, 55597453, 9067496, 51808079, 33312638, 25456129, 14121551, 54921728,
+    3972023,
+]);
+
 /// `L` is the order of base point, i.e. 2^252 +
 /// 27742317777372353535851937790883648493
 pub(crate) const L: Scalar29 = Scalar29([
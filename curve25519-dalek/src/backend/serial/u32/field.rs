@@ -45,7 +45,7 @@ use zeroize::Zeroize;
 /// # Note
 ///
 /// The `curve25519_dalek::field` module provides a type alias
-/// `curve25519_dalek::field::FieldElement` to either `FieldElement51`
+/// `curve25519_dalek::field::BackendFieldElement` to either `FieldElement51`
 /// or `FieldElement2625`.
 ///
 /// The backend-specific type `FieldElement2625` should not be used
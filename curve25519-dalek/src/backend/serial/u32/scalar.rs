@@ -13,6 +13,8 @@
 use core::fmt::Debug;
 use core::ops::{Index, IndexMut};
 
+use subtle::{Choice, ConditionallySelectable};
+
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
@@ -36,6 +38,16 @@ impl Zeroize for Scalar29 {
     }
 }
 
+impl ConditionallySelectable for Scalar29 {
+    fn conditional_select(a: &Scalar29, b: &Scalar29, choice: Choice) -> Scalar29 {
+        let mut limbs = a.0;
+        for (limb, b) in limbs.iter_mut().zip(b.0.iter()) {
+            limb.conditional_assign(b, choice);
+        }
+        Scalar29(limbs)
+    }
+}
+
 impl Index<usize> for Scalar29 {
     type Output = u32;
     fn index(&self, _index: usize) -> &u32 {
@@ -13,9 +13,16 @@
 
 use super::field::FieldElement51;
 use super::scalar::Scalar52;
+// The point constants below are expressed in terms of the active backend's
+// `EdwardsPoint`, so they are omitted when this module is only compiled as a
+// reference for the emulated risc0 backend's differential tests.
+#[cfg(not(curve25519_dalek_backend = "risc0-emulated"))]
 use crate::edwards::EdwardsPoint;
 
-#[cfg(feature = "precomputed-tables")]
+#[cfg(all(
+    feature = "precomputed-tables",
+    not(curve25519_dalek_backend = "risc0-emulated")
+))]
 use crate::{
     backend::serial::curve_models::AffineNielsPoint,
     edwards::EdwardsBasepointTable,
@@ -94,6 +101,49 @@ pub(crate) const SQRT_M1: FieldElement51 = FieldElement51::from_limbs([
     765476049583133,
 ]);
 
+/// `SQRT_ID` is the nonnegative square root of `i*d`, where `i = sqrt(-1)` and `d` is the Edwards
+/// curve parameter. (This is used internally by the inverse of the Ristretto Elligator map.)
+pub(crate) const SQRT_ID: FieldElement51 = FieldElement51::from_limbs([
+    47052614278056,
+    1585346747125414,
+    2161332085781156,
+    1631377194372281,
+    100284626847678,
+]);
+
+/// `FIELD_TWO_INV` is the inverse of 2 in the base field, `(p+1)/2`. (This is used by the `ff`
+/// implementation for `FieldElement`.)
+#[cfg(all(feature = "group", feature = "hazmat"))]
+pub(crate) const FIELD_TWO_INV: FieldElement51 = FieldElement51::from_limbs([
+    2251799813685239,
+    2251799813685247,
+    2251799813685247,
+    2251799813685247,
+    1125899906842623,
+]);
+
+/// `FIELD_MULTIPLICATIVE_GENERATOR` is 2, which generates the multiplicative group of the base
+/// field. (This is used by the `ff` implementation for `FieldElement`.)
+#[cfg(all(feature = "group", feature = "hazmat"))]
+pub(crate) const FIELD_MULTIPLICATIVE_GENERATOR: FieldElement51 =
+    FieldElement51::from_limbs([2, 0, 0, 0, 0]);
+
+/// `FIELD_ROOT_OF_UNITY_INV` is `-SQRT_M1`, the inverse of the primitive 4th root of unity
+/// `SQRT_M1 = 2^((p-1)/4)`. (This is used by the `ff` implementation for `FieldElement`.)
+#[cfg(all(feature = "group", feature = "hazmat"))]
+pub(crate) const FIELD_ROOT_OF_UNITY_INV: FieldElement51 = FieldElement51::from_limbs([
+    533094393274173,
+    2016890930128738,
+    18285341111199,
+    134597186663265,
+    1486323764102114,
+]);
+
+/// `FIELD_DELTA` is `2^4 = 16`, the multiplicative generator raised to `2^S`. (This is used by
+/// the `ff` implementation for `FieldElement`.)
+#[cfg(all(feature = "group", feature = "hazmat"))]
+pub(crate) const FIELD_DELTA: FieldElement51 = FieldElement51::from_limbs([16, 0, 0, 0, 0]);
+
 /// `APLUS2_OVER_FOUR` is (A+2)/4. (This is used internally within the Montgomery ladder.)
 pub(crate) const APLUS2_OVER_FOUR: FieldElement51 =
     FieldElement51::from_limbs([121666, 0, 0, 0, 0]);
@@ -113,6 +163,16 @@ pub(crate) const MONTGOMERY_A_NEG: FieldElement51 = FieldElement51::from_limbs([
     2251799813685247,
 ]);
 
+/// `ED25519_SQRTAM2` is the nonnegative square root of -(A+2) = -486664, used by the rational
+/// map from Curve25519 to edwards25519.
+pub(crate) const ED25519_SQRTAM2: FieldElement51 = FieldElement51::from_limbs([
+    1693982333959686,
+    608509411481997,
+    2235573344831311,
+    947681270984193,
+    266558006233600,
+]);
+
 /// `L` is the order of base point, i.e. 2^252 + 27742317777372353535851937790883648493
 pub(crate) const L: Scalar52 = Scalar52([
     0x0002631a5cf5d3ed,
@@ -148,6 +208,7 @@ pub(crate) const RR: Scalar52 = Scalar52([
 /// This is called `_POINT` to distinguish it from
 /// `ED25519_BASEPOINT_TABLE`, which should be used for scalar
 /// multiplication (it's much faster).
+#[cfg(not(curve25519_dalek_backend = "risc0-emulated"))]
 pub const ED25519_BASEPOINT_POINT: EdwardsPoint = EdwardsPoint {
     X: FieldElement51::from_limbs([
         1738742601995546,
@@ -181,10 +242,12 @@ pub const ED25519_BASEPOINT_POINT: EdwardsPoint = EdwardsPoint {
 ///
 /// Thus \\(\mathcal E\[4\]\\) is the points indexed by `0,2,4,6`, and
 /// \\(\mathcal E\[2\]\\) is the points indexed by `0,4`.
+#[cfg(not(curve25519_dalek_backend = "risc0-emulated"))]
 pub const EIGHT_TORSION: [EdwardsPoint; 8] = EIGHT_TORSION_INNER_DOC_HIDDEN;
 
 /// Inner item used to hide limb constants from cargo doc output.
 #[doc(hidden)]
+#[cfg(not(curve25519_dalek_backend = "risc0-emulated"))]
 pub const EIGHT_TORSION_INNER_DOC_HIDDEN: [EdwardsPoint; 8] = [
     EdwardsPoint {
         X: FieldElement51::from_limbs([0, 0, 0, 0, 0]),
@@ -327,13 +390,19 @@ pub const EIGHT_TORSION_INNER_DOC_HIDDEN: [EdwardsPoint; 8] = [
 ];
 
 /// Table containing precomputed multiples of the Ed25519 basepoint \\(B = (x, 4/5)\\).
-#[cfg(feature = "precomputed-tables")]
+#[cfg(all(
+    feature = "precomputed-tables",
+    not(curve25519_dalek_backend = "risc0-emulated")
+))]
 pub static ED25519_BASEPOINT_TABLE: &EdwardsBasepointTable =
     &ED25519_BASEPOINT_TABLE_INNER_DOC_HIDDEN;
 
 /// Inner constant, used to avoid filling the docs with precomputed points.
 #[doc(hidden)]
-#[cfg(feature = "precomputed-tables")]
+#[cfg(all(
+    feature = "precomputed-tables",
+    not(curve25519_dalek_backend = "risc0-emulated")
+))]
 static ED25519_BASEPOINT_TABLE_INNER_DOC_HIDDEN: EdwardsBasepointTable = EdwardsBasepointTable([
     LookupTable([
         AffineNielsPoint {
@@ -6290,7 +6359,10 @@ static ED25519_BASEPOINT_TABLE_INNER_DOC_HIDDEN: EdwardsBasepointTable = Edwards
 ]);
 
 /// Odd multiples of the basepoint `[B, 3B, 5B, 7B, 9B, 11B, 13B, 15B, ..., 127B]`.
-#[cfg(feature = "precomputed-tables")]
+#[cfg(all(
+    feature = "precomputed-tables",
+    not(curve25519_dalek_backend = "risc0-emulated")
+))]
 #[allow(dead_code)]
 pub(crate) const AFFINE_ODD_MULTIPLES_OF_BASEPOINT: NafLookupTable8<AffineNielsPoint> =
     NafLookupTable8([
@@ -14,10 +14,12 @@
 use core::fmt::Debug;
 use core::ops::{Index, IndexMut};
 
+use subtle::{Choice, ConditionallySelectable};
+
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
-use crate::constants;
+use super::constants;
 
 /// The `Scalar52` struct represents an element in
 /// \\(\mathbb Z / \ell \mathbb Z\\) as 5 \\(52\\)-bit limbs.
@@ -37,6 +39,16 @@ impl Zeroize for Scalar52 {
     }
 }
 
+impl ConditionallySelectable for Scalar52 {
+    fn conditional_select(a: &Scalar52, b: &Scalar52, choice: Choice) -> Scalar52 {
+        let mut limbs = a.0;
+        for (limb, b) in limbs.iter_mut().zip(b.0.iter()) {
+            limb.conditional_assign(b, choice);
+        }
+        Scalar52(limbs)
+    }
+}
+
 impl Index<usize> for Scalar52 {
     type Output = u64;
     fn index(&self, _index: usize) -> &u64 {
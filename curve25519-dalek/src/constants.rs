@@ -28,7 +28,10 @@ cfg_if! {
         pub use crate::backend::serial::fiat_u32::constants::*;
         #[cfg(curve25519_dalek_bits = "64")]
         pub use crate::backend::serial::fiat_u64::constants::*;
-    } else if  #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))] {
+    } else if  #[cfg(any(
+        curve25519_dalek_backend = "risc0",
+        curve25519_dalek_backend = "risc0-emulated"
+    ))] {
         pub use crate::backend::serial::risc0::constants::*;
     } else {
         #[cfg(curve25519_dalek_bits = "32")]
@@ -96,7 +99,7 @@ pub static RISTRETTO_BASEPOINT_TABLE: &RistrettoBasepointTable = unsafe {
 #[cfg(test)]
 mod test {
     use crate::constants;
-    use crate::field::FieldElement;
+    use crate::field::BackendFieldElement as FieldElement;
     use crate::traits::{IsIdentity, ValidityCheck};
 
     #[test]
@@ -126,6 +129,16 @@ mod test {
         }
     }
 
+    /// Test that ED25519_SQRTAM2 is the nonnegative square root of -(A+2)
+    #[test]
+    fn test_sqrt_minus_aplus2() {
+        let two = &FieldElement::ONE + &FieldElement::ONE;
+        let minus_aplus2 = -&(&constants::MONTGOMERY_A + &two);
+        let sqrt_sq = constants::ED25519_SQRTAM2.square();
+        assert_eq!(minus_aplus2, sqrt_sq);
+        assert!(bool::from(!constants::ED25519_SQRTAM2.is_negative()));
+    }
+
     /// Test that SQRT_M1 is the positive square root of -1
     #[test]
     fn test_sqrt_minus_one() {
@@ -135,6 +148,15 @@ mod test {
         assert!(bool::from(!constants::SQRT_M1.is_negative()));
     }
 
+    /// Test that SQRT_ID is the positive square root of i*d
+    #[test]
+    fn test_sqrt_id() {
+        let id = &constants::SQRT_M1 * &constants::EDWARDS_D;
+        let sqrt_id_sq = constants::SQRT_ID.square();
+        assert_eq!(id, sqrt_id_sq);
+        assert!(bool::from(!constants::SQRT_ID.is_negative()));
+    }
+
     #[test]
     fn test_sqrt_constants_sign() {
         let minus_one = FieldElement::MINUS_ONE;
@@ -149,7 +171,8 @@ mod test {
     #[cfg(all(
         curve25519_dalek_bits = "32",
         not(curve25519_dalek_backend = "fiat"),
-        not(target_os = "zkvm")
+        not(curve25519_dalek_backend = "risc0-emulated"),
+        not(curve25519_dalek_backend = "risc0")
     ))]
     fn test_d_vs_ratio() {
         use crate::backend::serial::u32::field::FieldElement2625;
@@ -163,7 +186,11 @@ mod test {
 
     /// Test that d = -121665/121666
     #[test]
-    #[cfg(all(curve25519_dalek_bits = "64", not(curve25519_dalek_backend = "fiat")))]
+    #[cfg(all(
+        curve25519_dalek_bits = "64",
+        not(curve25519_dalek_backend = "fiat"),
+        not(curve25519_dalek_backend = "risc0-emulated")
+    ))]
     fn test_d_vs_ratio() {
         use crate::backend::serial::u64::field::FieldElement51;
         let a = -&FieldElement51([121665, 0, 0, 0, 0]);
@@ -176,7 +203,10 @@ mod test {
 
     /// Test that d = -121665/121666
     #[test]
-    #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))]
+    #[cfg(any(
+        curve25519_dalek_backend = "risc0",
+        curve25519_dalek_backend = "risc0-emulated"
+    ))]
     fn test_d_vs_ratio() {
         use crate::backend::serial::risc0::field::FieldElementR0;
         use crypto_bigint::U256;
@@ -16,6 +16,14 @@ compile_error!("curve25519_dalek_backend is 'serial'");
 #[cfg(curve25519_dalek_backend = "simd")]
 compile_error!("curve25519_dalek_backend is 'simd'");
 
+// risc0 was assumed inside the zkVM or overriden
+#[cfg(curve25519_dalek_backend = "risc0")]
+compile_error!("curve25519_dalek_backend is 'risc0'");
+
+// risc0-emulated was overriden
+#[cfg(curve25519_dalek_backend = "risc0-emulated")]
+compile_error!("curve25519_dalek_backend is 'risc0-emulated'");
+
 // 32 bits target_pointer_width was assumed or overriden
 #[cfg(curve25519_dalek_bits = "32")]
 compile_error!("curve25519_dalek_bits is '32'");
@@ -105,7 +105,7 @@ use core::ops::{Mul, MulAssign};
 use cfg_if::cfg_if;
 
 #[cfg(feature = "digest")]
-use digest::{generic_array::typenum::U64, Digest};
+use digest::{crypto_common::BlockSizeUser, generic_array::typenum::U64, Digest};
 
 #[cfg(feature = "group")]
 use {
@@ -124,10 +124,13 @@ use zeroize::Zeroize;
 
 use crate::constants;
 
-use crate::field::FieldElement;
+use crate::field::BackendFieldElement as FieldElement;
 use crate::scalar::{clamp_integer, Scalar};
 
-use crate::montgomery::MontgomeryPoint;
+#[cfg(feature = "digest")]
+use crate::hash_to_curve;
+
+use crate::montgomery::{self, MontgomeryPoint};
 
 use crate::backend::serial::curve_models::AffineNielsPoint;
 use crate::backend::serial::curve_models::CompletedPoint;
@@ -247,6 +250,8 @@ impl TryFrom<&[u8]> for CompressedEdwardsY {
     }
 }
 
+define_hex_encoding!(CompressedEdwardsY);
+
 // ------------------------------------------------------------------------
 // Serde support
 // ------------------------------------------------------------------------
@@ -573,6 +578,60 @@ impl EdwardsPoint {
         CompressedEdwardsY(s)
     }
 
+    /// Compress this point to `CompressedEdwardsY` format, failing closed if the arithmetic
+    /// behind it cannot be trusted.
+    ///
+    /// With the RISC Zero backend, this returns `None` if the host has been caught misbehaving
+    /// at any point so far, as reported by `risc0_fault::host_misbehaved`, since the point and
+    /// its encoding may then be meaningless. With every other backend this always succeeds.
+    pub fn try_compress(&self) -> Option<CompressedEdwardsY> {
+        let compressed = self.compress();
+
+        #[cfg(any(
+            curve25519_dalek_backend = "risc0",
+            curve25519_dalek_backend = "risc0-emulated"
+        ))]
+        if crate::risc0_fault::host_misbehaved() {
+            return None;
+        }
+
+        Some(compressed)
+    }
+
+    /// Check whether `compressed` is the encoding of this point, with the same result as
+    /// `self.compress() == *compressed`.
+    ///
+    /// With the RISC Zero backend, rather than compressing `self`, which costs a field inversion,
+    /// this decompresses `compressed` and compares the two points projectively, with
+    /// \\(X_1 Z_2 = X_2 Z_1\\) and \\(Y_1 Z_2 = Y_2 Z_1\\). Non-canonical encodings never match.
+    /// It returns `false` if the host has been caught misbehaving at any point so far, as
+    /// reported by `risc0_fault::host_misbehaved`. With every other backend, where a
+    /// decompression costs about as much as the inversion it would save, this compresses `self`.
+    pub fn eq_compressed(&self, compressed: &CompressedEdwardsY) -> bool {
+        cfg_if! {
+            if #[cfg(any(
+                curve25519_dalek_backend = "risc0",
+                curve25519_dalek_backend = "risc0-emulated"
+            ))] {
+                let decompressed = match compressed.decompress() {
+                    Some(point) => point,
+                    None => return false,
+                };
+
+                // The decompressed point has Z = 1, so its canonical encoding needs no
+                // inversion. It differs from `compressed` if the y-coordinate was not reduced, or
+                // if the sign bit was set for x = 0.
+                let mut canonical = decompressed.Y.as_bytes();
+                canonical[31] ^= decompressed.X.is_negative().unwrap_u8() << 7;
+                let matches = canonical == compressed.0 && *self == decompressed;
+
+                matches && !crate::risc0_fault::host_misbehaved()
+            } else {
+                self.compress() == *compressed
+            }
+        }
+    }
+
     #[cfg(feature = "digest")]
     /// Maps the digest of the input bytes to the curve. This is NOT a hash-to-curve function, as
     /// it produces points with a non-uniform distribution. Rather, it performs something that
@@ -597,13 +656,63 @@ impl EdwardsPoint {
 
         let fe = FieldElement::from_bytes(&res);
 
-        let M1 = crate::montgomery::elligator_encode(&fe);
+        let M1 = montgomery::elligator_encode(&fe);
         let E1_opt = M1.to_edwards(sign_bit);
 
         E1_opt
             .expect("Montgomery conversion to Edwards point in Elligator failed")
             .mul_by_cofactor()
     }
+
+    #[cfg(feature = "digest")]
+    /// Hash a message to the curve with the `hash_to_curve` function of
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-3), using `D` for
+    /// `expand_message_xmd` and the domain separation tag `dst`.
+    ///
+    /// With `D = Sha512`, this is the `edwards25519_XMD:SHA-512_ELL2_RO_` suite. Its output is
+    /// indistinguishable from a random point of the prime-order subgroup, as required by
+    /// protocols modelling the hash as a random oracle.
+    ///
+    /// Domain separation tags longer than 255 bytes are hashed down as specified in RFC 9380
+    /// §5.3.3.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::edwards::EdwardsPoint;
+    /// use sha2::Sha512;
+    ///
+    /// let dst = b"MyApp-V01-CS01-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+    /// let P = EdwardsPoint::hash_to_curve::<Sha512>(b"msg", dst);
+    /// assert!(P.is_torsion_free());
+    /// ```
+    pub fn hash_to_curve<D>(msg: &[u8], dst: &[u8]) -> EdwardsPoint
+    where
+        D: Digest<OutputSize = U64> + BlockSizeUser + Default,
+    {
+        let mut u = [FieldElement::ZERO; 2];
+        hash_to_curve::hash_to_field::<D>(&[msg], dst, &mut u);
+        let Q0 = hash_to_curve::map_to_curve(&u[0]);
+        let Q1 = hash_to_curve::map_to_curve(&u[1]);
+        (Q0 + Q1).mul_by_cofactor()
+    }
+
+    #[cfg(feature = "digest")]
+    /// Hash a message to the curve with the `encode_to_curve` function of
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-3), using `D` for
+    /// `expand_message_xmd` and the domain separation tag `dst`.
+    ///
+    /// With `D = Sha512`, this is the `edwards25519_XMD:SHA-512_ELL2_NU_` suite. It costs about
+    /// half as much as [`EdwardsPoint::hash_to_curve`], but its output is not uniformly
+    /// distributed, so it must only be used by protocols that allow a nonuniform encoding.
+    pub fn encode_to_curve<D>(msg: &[u8], dst: &[u8]) -> EdwardsPoint
+    where
+        D: Digest<OutputSize = U64> + BlockSizeUser + Default,
+    {
+        let mut u = [FieldElement::ZERO; 1];
+        hash_to_curve::hash_to_field::<D>(&[msg], dst, &mut u);
+        hash_to_curve::map_to_curve(&u[0]).mul_by_cofactor()
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -783,6 +892,18 @@ impl EdwardsPoint {
         };
         Self::mul_base(&s)
     }
+
+    /// Given a big-endian bit representation of an integer \\(n\\), return \\( \[n\]P \\),
+    /// where \\(P\\) is `self`. This is constant time in the length of `bits`.
+    ///
+    /// The multiplication runs the Montgomery ladder on the \\(u\\)-coordinate of `self`, like
+    /// [`MontgomeryPoint::mul_bits_be`], and then recovers the \\(v\\)-coordinate of the
+    /// result from `self`, so that the full point is returned without having to guess its sign.
+    /// Unlike multiplication by a [`Scalar`], `n` is not reduced modulo \\( \ell \\), so this
+    /// is correct for points with a torsion component.
+    pub fn mul_bits_be(&self, bits: impl Iterator<Item = bool>) -> EdwardsPoint {
+        montgomery::mul_bits_be_edwards(self, bits)
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -1591,7 +1712,7 @@ impl CofactorGroup for EdwardsPoint {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{field::FieldElement, scalar::Scalar};
+    use crate::{field::BackendFieldElement as FieldElement, scalar::Scalar};
     use subtle::ConditionallySelectable;
 
     #[cfg(feature = "alloc")]
@@ -1670,6 +1791,47 @@ mod test {
         assert_eq!(bp.compress(), constants::ED25519_BASEPOINT_COMPRESSED);
     }
 
+    /// Test that `try_compress` agrees with `compress`. With the RISC Zero backend this is
+    /// tested together with fault injection in `backend::serial::risc0::field`.
+    #[test]
+    #[cfg(not(any(
+        curve25519_dalek_backend = "risc0",
+        curve25519_dalek_backend = "risc0-emulated"
+    )))]
+    fn try_compress() {
+        let bp = constants::ED25519_BASEPOINT_POINT;
+        assert_eq!(bp.try_compress(), Some(bp.compress()));
+        let bp2 = bp + bp;
+        assert_eq!(bp2.try_compress(), Some(BASE2_CMPRSSD));
+    }
+
+    /// Test that `eq_compressed` agrees with comparing compressions, including for
+    /// non-canonical encodings.
+    #[test]
+    fn eq_compressed() {
+        let bp = constants::ED25519_BASEPOINT_POINT;
+        let bp2 = bp + bp;
+        assert!(bp.eq_compressed(&constants::ED25519_BASEPOINT_COMPRESSED));
+        assert!(bp2.eq_compressed(&BASE2_CMPRSSD));
+        assert!(!bp.eq_compressed(&BASE2_CMPRSSD));
+        assert!(!(-bp).eq_compressed(&constants::ED25519_BASEPOINT_COMPRESSED));
+
+        // The identity (0, 1) with the sign bit set, and with y = p + 1
+        let identity = EdwardsPoint::identity();
+        let mut negative_zero = [0u8; 32];
+        negative_zero[0] = 1;
+        negative_zero[31] = 0x80;
+        let mut unreduced = [0xffu8; 32];
+        unreduced[0] = 0xee;
+        unreduced[31] = 0x7f;
+        for bytes in [negative_zero, unreduced] {
+            let compressed = CompressedEdwardsY(bytes);
+            assert_eq!(compressed.decompress(), Some(identity));
+            assert!(!identity.eq_compressed(&compressed));
+        }
+        assert!(identity.eq_compressed(&identity.compress()));
+    }
+
     /// Test sign handling in decompression
     #[test]
     fn decompression_sign_handling() {
@@ -1929,6 +2091,50 @@ mod test {
         }
     }
 
+    /// Return the bits of a little-endian byte string, most significant first.
+    fn bits_be(bytes: &[u8]) -> impl Iterator<Item = bool> + '_ {
+        (0..bytes.len() * 8)
+            .rev()
+            .map(move |i| ((bytes[i >> 3] >> (i & 7)) & 1) == 1)
+    }
+
+    /// Check that mul_bits_be agrees with scalar multiplication, including on torsion points
+    /// and in the cases where the y-recovery formulas are exceptional
+    #[test]
+    fn mul_bits_be() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+
+        // Small multiples of every point of small order, with and without a prime-order part.
+        for i in 0..8 {
+            for P in [constants::EIGHT_TORSION[i], B + constants::EIGHT_TORSION[i]] {
+                for n in 0u8..20 {
+                    assert_eq!(P.mul_bits_be(bits_be(&[n])), Scalar::from(n) * P);
+                }
+                assert!(P.mul_bits_be(core::iter::empty()).is_identity());
+            }
+        }
+
+        // Multiplying by l and l - 1 gives the identity and -B.
+        let l = constants::BASEPOINT_ORDER_PRIVATE.to_bytes();
+        assert!(B.mul_bits_be(bits_be(&l)).is_identity());
+        let l_minus_one = (-Scalar::ONE).to_bytes();
+        assert_eq!(B.mul_bits_be(bits_be(&l_minus_one)), -B);
+
+        // Random integers, which are not reduced, on random points with a torsion component.
+        let mut csprng = rand_core::OsRng;
+        for i in 0..16 {
+            let Q = EdwardsPoint::mul_base(&Scalar::random(&mut csprng));
+            let P = Q + constants::EIGHT_TORSION[i % 8];
+            let mut k = [0u8; 32];
+            csprng.fill_bytes(&mut k);
+            let torsion = (usize::from(k[0] & 7) * (i % 8)) % 8;
+            let expected = Scalar::from_bytes_mod_order(k) * Q + constants::EIGHT_TORSION[torsion];
+            let result = P.mul_bits_be(bits_be(&k));
+            assert_eq!(result, expected);
+            assert!(result.is_valid());
+        }
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn impl_sum() {
@@ -0,0 +1,216 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Text encodings of scalars and compressed points.
+//!
+//! [`Scalar`] implements [`FromStr`](core::str::FromStr), accepting either a decimal integer
+//! without leading zeros or a big-endian hexadecimal integer prefixed with `0x`, and formats as
+//! decimal with [`Display`](core::fmt::Display) and as 64 big-endian hex digits with
+//! [`LowerHex`](core::fmt::LowerHex). Only the `0x`-prefixed `{:#x}` output parses back, since
+//! unprefixed digits are read as decimal.
+//!
+//! [`CompressedEdwardsY`], [`CompressedRistretto`] and [`MontgomeryPoint`] are byte strings
+//! rather than integers, so they are written as exactly 64 hex digits in wire order, optionally
+//! prefixed with `0x`. Parsing one of these does not check that it decodes to a point.
+//!
+//! None of these conversions run in constant time, so they should not be used on secret values.
+//!
+//! [`Scalar`]: crate::scalar::Scalar
+//! [`CompressedEdwardsY`]: crate::edwards::CompressedEdwardsY
+//! [`CompressedRistretto`]: crate::ristretto::CompressedRistretto
+//! [`MontgomeryPoint`]: crate::montgomery::MontgomeryPoint
+
+use core::fmt;
+
+/// The error returned when parsing a scalar or a compressed point from a string fails.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ParseError {
+    /// The string has no digits.
+    Empty,
+    /// The string contains a character that is not a digit in the expected radix.
+    InvalidDigit,
+    /// A decimal integer has a leading zero, as unprefixed hex output would.
+    LeadingZero,
+    /// A compressed point was not given as exactly 64 hex digits.
+    InvalidLength,
+    /// The integer is not less than the group order \\( \ell \\).
+    OutOfRange,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParseError::Empty => "cannot parse from an empty string",
+            ParseError::InvalidDigit => "invalid digit found in string",
+            ParseError::LeadingZero => "decimal integer has a leading zero",
+            ParseError::InvalidLength => "expected exactly 64 hex digits",
+            ParseError::OutOfRange => "integer is not less than the group order",
+        })
+    }
+}
+
+/// Strip an optional `0x` or `0X` prefix, returning whether one was present.
+pub(crate) fn strip_hex_prefix(s: &str) -> (bool, &str) {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(digits) => (true, digits),
+        None => (false, s),
+    }
+}
+
+/// Parse an unsigned integer written in `radix` into its 256-bit little-endian encoding.
+pub(crate) fn parse_u256(digits: &str, radix: u32) -> Result<[u8; 32], ParseError> {
+    if digits.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut acc = [0u8; 32];
+    for c in digits.chars() {
+        let mut carry = c.to_digit(radix).ok_or(ParseError::InvalidDigit)?;
+        // acc = acc * radix + digit, one byte at a time
+        for limb in acc.iter_mut() {
+            let v = (*limb as u32) * radix + carry;
+            *limb = v as u8;
+            carry = v >> 8;
+        }
+        if carry != 0 {
+            return Err(ParseError::OutOfRange);
+        }
+    }
+
+    Ok(acc)
+}
+
+/// Parse exactly 64 hex digits, optionally prefixed with `0x`, into 32 bytes in the order written.
+pub(crate) fn parse_hex_bytes(s: &str) -> Result<[u8; 32], ParseError> {
+    let (_, digits) = strip_hex_prefix(s);
+    if digits.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    if digits.len() != 64 {
+        return Err(ParseError::InvalidLength);
+    }
+
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(digits, &mut bytes).map_err(|_| ParseError::InvalidDigit)?;
+    Ok(bytes)
+}
+
+/// Write `bytes` as 64 lowercase hex digits in the order given, honouring the `#` flag and
+/// padding options of `f`.
+pub(crate) fn fmt_hex_bytes(bytes: &[u8; 32], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut buf = [0u8; 64];
+    hex::encode_to_slice(bytes, &mut buf).map_err(|_| fmt::Error)?;
+    let s = core::str::from_utf8(&buf).map_err(|_| fmt::Error)?;
+    f.pad_integral(true, "0x", s)
+}
+
+/// Write the 256-bit little-endian integer `le_bytes` in decimal, honouring the padding
+/// options of `f`.
+pub(crate) fn fmt_u256_decimal(le_bytes: &[u8; 32], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    // 2^256 has 78 decimal digits
+    let mut buf = [0u8; 78];
+    let mut start = buf.len();
+    let mut n = *le_bytes;
+    loop {
+        // n, rem = n / 10, n % 10, from the most significant byte down
+        let mut rem = 0u32;
+        for limb in n.iter_mut().rev() {
+            let v = (rem << 8) | (*limb as u32);
+            *limb = (v / 10) as u8;
+            rem = v % 10;
+        }
+        start -= 1;
+        buf[start] = b'0' + rem as u8;
+        if n == [0u8; 32] {
+            break;
+        }
+    }
+
+    let s = core::str::from_utf8(&buf[start..]).map_err(|_| fmt::Error)?;
+    f.pad_integral(true, "", s)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::edwards::CompressedEdwardsY;
+    use crate::montgomery::MontgomeryPoint;
+    use crate::ristretto::CompressedRistretto;
+    use crate::{constants, traits::Identity};
+
+    #[test]
+    fn parse_u256_limits() {
+        let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        assert_eq!(parse_u256(max, 10), Ok([0xff; 32]));
+        let over = "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+        assert_eq!(parse_u256(over, 10), Err(ParseError::OutOfRange));
+
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        assert_eq!(
+            parse_u256(
+                "0000000000000000000000000000000000000000000000000000000000000000000001",
+                16
+            ),
+            Ok(one)
+        );
+        assert_eq!(parse_u256("", 10), Err(ParseError::Empty));
+        assert_eq!(parse_u256("12a", 10), Err(ParseError::InvalidDigit));
+        assert_eq!(parse_u256("-1", 10), Err(ParseError::InvalidDigit));
+    }
+
+    #[test]
+    fn compressed_points_hex_round_trip() {
+        let edwards = constants::ED25519_BASEPOINT_COMPRESSED;
+        let hex = "5866666666666666666666666666666666666666666666666666666666666666";
+        assert_eq!(format!("{:x}", edwards), hex);
+        assert_eq!(format!("{}", edwards), hex);
+        assert_eq!(format!("{:#x}", edwards), format!("0x{}", hex));
+        assert_eq!(hex.parse::<CompressedEdwardsY>(), Ok(edwards));
+        assert_eq!(
+            format!("0X{}", hex).parse::<CompressedEdwardsY>(),
+            Ok(edwards)
+        );
+
+        let ristretto = constants::RISTRETTO_BASEPOINT_COMPRESSED;
+        let hex = "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76";
+        assert_eq!(format!("{:x}", ristretto), hex);
+        assert_eq!(hex.parse::<CompressedRistretto>(), Ok(ristretto));
+        assert_eq!(
+            format!("{}", CompressedRistretto::identity()).parse::<CompressedRistretto>(),
+            Ok(CompressedRistretto::identity())
+        );
+
+        let montgomery = constants::X25519_BASEPOINT;
+        let hex = "0900000000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(format!("{}", montgomery), hex);
+        assert_eq!(hex.parse::<MontgomeryPoint>(), Ok(montgomery));
+    }
+
+    #[test]
+    fn compressed_points_reject_malformed_hex() {
+        let hex = "5866666666666666666666666666666666666666666666666666666666666666";
+        assert_eq!("".parse::<CompressedEdwardsY>(), Err(ParseError::Empty));
+        assert_eq!("0x".parse::<MontgomeryPoint>(), Err(ParseError::Empty));
+        assert_eq!(
+            hex[..62].parse::<CompressedEdwardsY>(),
+            Err(ParseError::InvalidLength)
+        );
+        assert_eq!(
+            format!("{}00", hex).parse::<CompressedRistretto>(),
+            Err(ParseError::InvalidLength)
+        );
+        assert_eq!(
+            hex.replace('8', "g").parse::<CompressedEdwardsY>(),
+            Err(ParseError::InvalidDigit)
+        );
+        // Multi-byte characters are never hex digits
+        assert_eq!(
+            format!("é{}", &hex[2..]).parse::<MontgomeryPoint>(),
+            Err(ParseError::InvalidDigit)
+        );
+    }
+}
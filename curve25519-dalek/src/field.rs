@@ -12,9 +12,9 @@
 //! Field arithmetic modulo \\(p = 2\^{255} - 19\\).
 //!
 //! The `curve25519_dalek::field` module provides a type alias
-//! `curve25519_dalek::field::FieldElement` to a field element type
-//! defined in the `backend` module; either `FieldElement51` or
-//! `FieldElement2625`.
+//! `curve25519_dalek::field::BackendFieldElement` to a field element type
+//! defined in the `backend` module; either `FieldElement51`,
+//! `FieldElement2625` or `FieldElementR0`.
 //!
 //! Field operations defined in terms of machine
 //! operations, such as field multiplication or squaring, are defined in
@@ -22,6 +22,14 @@
 //!
 //! Field operations defined in terms of other field operations, such as
 //! field inversion or square roots, are defined here.
+//!
+//! This module is only public when the `hazmat` feature is enabled, and
+//! then only exposes [`FieldElement`]. It wraps the field element of
+//! whichever backend is selected, and only exposes the operations listed
+//! on it, so code written against it builds on every backend. It offers
+//! none of the guarantees of the group types: nothing stops a caller from
+//! building points which are not on the curve, so it should only be used
+//! to implement protocols the rest of the crate does not cover.
 
 #![allow(unused_qualifications)]
 
@@ -33,74 +41,82 @@ use subtle::Choice;
 use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
+#[cfg(feature = "hazmat")]
+use subtle::CtOption;
 
 use crate::backend;
 use crate::constants;
 
+#[cfg(feature = "hazmat")]
+pub use self::hazmat::FieldElement;
+
 cfg_if! {
     if #[cfg(curve25519_dalek_backend = "fiat")] {
-        /// A `FieldElement` represents an element of the field
+        /// A `BackendFieldElement` represents an element of the field
         /// \\( \mathbb Z / (2\^{255} - 19)\\).
         ///
-        /// The `FieldElement` type is an alias for one of the platform-specific
+        /// The `BackendFieldElement` type is an alias for one of the platform-specific
         /// implementations.
         ///
         /// Using formally-verified field arithmetic from fiat-crypto.
         #[cfg(curve25519_dalek_bits = "32")]
-        pub(crate) type FieldElement = backend::serial::fiat_u32::field::FieldElement2625;
+        pub(crate) type BackendFieldElement = backend::serial::fiat_u32::field::FieldElement2625;
 
-        /// A `FieldElement` represents an element of the field
+        /// A `BackendFieldElement` represents an element of the field
         /// \\( \mathbb Z / (2\^{255} - 19)\\).
         ///
-        /// The `FieldElement` type is an alias for one of the platform-specific
+        /// The `BackendFieldElement` type is an alias for one of the platform-specific
         /// implementations.
         ///
         /// Using formally-verified field arithmetic from fiat-crypto.
         #[cfg(curve25519_dalek_bits = "64")]
-        pub(crate) type FieldElement = backend::serial::fiat_u64::field::FieldElement51;
-    } else if #[cfg(curve25519_dalek_bits = "64")] {
-        /// A `FieldElement` represents an element of the field
+        pub(crate) type BackendFieldElement = backend::serial::fiat_u64::field::FieldElement51;
+    } else if #[cfg(any(
+        curve25519_dalek_backend = "risc0",
+        curve25519_dalek_backend = "risc0-emulated"
+    ))] {
+        /// A `BackendFieldElement` represents an element of the field
         /// \\( \mathbb Z / (2\^{255} - 19)\\).
         ///
-        /// The `FieldElement` type is an alias for one of the platform-specific
+        /// The `BackendFieldElement` type is an alias for one of the platform-specific
         /// implementations.
-        pub(crate) type FieldElement = backend::serial::u64::field::FieldElement51;
-    } else if #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))] {
-        /// A `FieldElement` represents an element of the field
+        pub(crate) type BackendFieldElement = backend::serial::risc0::field::FieldElementR0;
+    } else if #[cfg(curve25519_dalek_bits = "64")] {
+        /// A `BackendFieldElement` represents an element of the field
         /// \\( \mathbb Z / (2\^{255} - 19)\\).
         ///
-        /// The `FieldElement` type is an alias for one of the platform-specific
+        /// The `BackendFieldElement` type is an alias for one of the platform-specific
         /// implementations.
-        pub(crate) type FieldElement = backend::serial::risc0::field::FieldElementR0;
+        pub(crate) type BackendFieldElement = backend::serial::u64::field::FieldElement51;
     } else {
-        /// A `FieldElement` represents an element of the field
+        /// A `BackendFieldElement` represents an element of the field
         /// \\( \mathbb Z / (2\^{255} - 19)\\).
         ///
-        /// The `FieldElement` type is an alias for one of the platform-specific
+        /// The `BackendFieldElement` type is an alias for one of the platform-specific
         /// implementations.
-        pub(crate) type FieldElement = backend::serial::u32::field::FieldElement2625;
+        pub(crate) type BackendFieldElement = backend::serial::u32::field::FieldElement2625;
     }
 }
 
-impl Eq for FieldElement {}
+impl Eq for BackendFieldElement {}
 
-impl PartialEq for FieldElement {
-    fn eq(&self, other: &FieldElement) -> bool {
+impl PartialEq for BackendFieldElement {
+    fn eq(&self, other: &BackendFieldElement) -> bool {
         self.ct_eq(other).into()
     }
 }
 
-impl ConstantTimeEq for FieldElement {
-    /// Test equality between two `FieldElement`s.  Since the
+impl ConstantTimeEq for BackendFieldElement {
+    /// Test equality between two `BackendFieldElement`s.  Since the
     /// internal representation is not canonical, the field elements
     /// are normalized to wire format before comparison.
-    fn ct_eq(&self, other: &FieldElement) -> Choice {
+    fn ct_eq(&self, other: &BackendFieldElement) -> Choice {
         self.as_bytes().ct_eq(&other.as_bytes())
     }
 }
 
-impl FieldElement {
-    /// Determine if this `FieldElement` is negative, in the sense
+impl BackendFieldElement {
+    /// Determine if this `BackendFieldElement` is negative, in the sense
     /// used in the ed25519 paper: `x` is negative if the low bit is
     /// set.
     ///
@@ -112,7 +128,7 @@ impl FieldElement {
         (bytes[0] & 1).into()
     }
 
-    /// Determine if this `FieldElement` is zero.
+    /// Determine if this `BackendFieldElement` is zero.
     ///
     /// # Return
     ///
@@ -127,7 +143,7 @@ impl FieldElement {
     /// Compute (self^(2^250-1), self^11), used as a helper function
     /// within invert() and pow22523().
     #[rustfmt::skip] // keep alignment of explanatory comments
-    fn pow22501(&self) -> (FieldElement, FieldElement) {
+    fn pow22501(&self) -> (BackendFieldElement, BackendFieldElement) {
         // Instead of managing which temporary variables are used
         // for what, we define as many as we need and leave stack
         // allocation to the compiler
@@ -163,20 +179,20 @@ impl FieldElement {
         (t19, t3)
     }
 
-    /// Given a slice of pub(crate)lic `FieldElements`, replace each with its inverse.
+    /// Given a slice of public `FieldElements`, replace each with its inverse.
     ///
-    /// When an input `FieldElement` is zero, its value is unchanged.
+    /// When an input `BackendFieldElement` is zero, its value is unchanged.
     #[cfg(feature = "alloc")]
-    pub(crate) fn batch_invert(inputs: &mut [FieldElement]) {
+    pub(crate) fn batch_invert(inputs: &mut [BackendFieldElement]) {
         // Montgomery’s Trick and Fast Implementation of Masked AES
         // Genelle, Prouff and Quisquater
         // Section 3.2
 
         let n = inputs.len();
-        let mut scratch = vec![FieldElement::ONE; n];
+        let mut scratch = vec![BackendFieldElement::ONE; n];
 
         // Keep an accumulator of all of the previous products
-        let mut acc = FieldElement::ONE;
+        let mut acc = BackendFieldElement::ONE;
 
         // Pass through the input vector, recording the previous
         // products in the scratch space
@@ -207,12 +223,22 @@ impl FieldElement {
     /// Given a nonzero field element, compute its inverse.
     ///
     /// The inverse is computed as self^(p-2), since
-    /// x^(p-2)x = x^(p-1) = 1 (mod p).
+    /// x^(p-2)x = x^(p-1) = 1 (mod p). On the R0 backend, it is
+    /// instead taken from the host if a `risc0_hint` handler is
+    /// installed, and checked with one multiplication.
     ///
     /// This function returns zero on input zero.
     #[rustfmt::skip] // keep alignment of explanatory comments
     #[allow(clippy::let_and_return)]
-    pub(crate) fn invert(&self) -> FieldElement {
+    pub(crate) fn invert(&self) -> BackendFieldElement {
+        #[cfg(any(
+            curve25519_dalek_backend = "risc0",
+            curve25519_dalek_backend = "risc0-emulated"
+        ))]
+        if let Some(inverse) = self.invert_hinted() {
+            return inverse;
+        }
+
         // The bits of p-2 = 2^255 -19 -2 are 11010111111...11.
         //
         //                                 nonzero bits of exponent
@@ -226,7 +252,7 @@ impl FieldElement {
     /// Raise this field element to the power (p-5)/8 = 2^252 -3.
     #[rustfmt::skip] // keep alignment of explanatory comments
     #[allow(clippy::let_and_return)]
-    fn pow_p58(&self) -> FieldElement {
+    fn pow_p58(&self) -> BackendFieldElement {
         // The bits of (p-5)/8 are 101111.....11.
         //
         //                                 nonzero bits of exponent
@@ -240,7 +266,10 @@ impl FieldElement {
     /// Given `FieldElements` `u` and `v`, compute either `sqrt(u/v)`
     /// or `sqrt(i*u/v)` in constant time.
     ///
-    /// This function always returns the nonnegative square root.
+    /// This function always returns the nonnegative square root. On
+    /// the R0 backend, the root is taken from the host if a
+    /// `risc0_hint` handler is installed, and checked with three
+    /// multiplications.
     ///
     /// # Return
     ///
@@ -249,7 +278,18 @@ impl FieldElement {
     /// - `(Choice(0), zero)        ` if `v` is zero and `u` is nonzero;
     /// - `(Choice(0), +sqrt(i*u/v))` if `u/v` is nonsquare (so `i*u/v` is square).
     ///
-    pub(crate) fn sqrt_ratio_i(u: &FieldElement, v: &FieldElement) -> (Choice, FieldElement) {
+    pub(crate) fn sqrt_ratio_i(
+        u: &BackendFieldElement,
+        v: &BackendFieldElement,
+    ) -> (Choice, BackendFieldElement) {
+        #[cfg(any(
+            curve25519_dalek_backend = "risc0",
+            curve25519_dalek_backend = "risc0-emulated"
+        ))]
+        if let Some(result) = BackendFieldElement::sqrt_ratio_i_hinted(u, v) {
+            return result;
+        }
+
         // Using the same trick as in ed25519 decoding, we merge the
         // inversion, the square root, and the square test as follows.
         //
@@ -309,14 +349,567 @@ impl FieldElement {
     /// - `(Choice(0), zero)           ` if `self` is zero;
     /// - `(Choice(0), +sqrt(i/self))  ` if `self` is a nonzero nonsquare;
     ///
-    pub(crate) fn invsqrt(&self) -> (Choice, FieldElement) {
-        FieldElement::sqrt_ratio_i(&FieldElement::ONE, self)
+    pub(crate) fn invsqrt(&self) -> (Choice, BackendFieldElement) {
+        BackendFieldElement::sqrt_ratio_i(&BackendFieldElement::ONE, self)
+    }
+
+    /// Compute the nonnegative square root of this `BackendFieldElement` in
+    /// constant time. On the R0 backend, it is taken from the host if a
+    /// `risc0_hint` handler is installed, as in `sqrt_ratio_i`.
+    ///
+    /// # Return
+    ///
+    /// - `Some(+sqrt(self))` if `self` is a square, including zero;
+    /// - `None` if `self` is a nonsquare.
+    #[cfg(feature = "hazmat")]
+    pub(crate) fn sqrt(&self) -> CtOption<BackendFieldElement> {
+        #[cfg(any(
+            curve25519_dalek_backend = "risc0",
+            curve25519_dalek_backend = "risc0-emulated"
+        ))]
+        if let Some((was_square, r)) =
+            BackendFieldElement::sqrt_ratio_i_hinted(self, &BackendFieldElement::ONE)
+        {
+            return CtOption::new(r, was_square);
+        }
+
+        // This is `sqrt_ratio_i(self, 1)`, with the multiplications by
+        // v = 1 left out: r = self^((p+3)/8), and r^2 = ±self when self
+        // is square. The root of a nonsquare is not needed.
+        let mut r = self * &self.pow_p58();
+        let check = r.square();
+
+        let correct_sign_sqrt = check.ct_eq(self);
+        let flipped_sign_sqrt = check.ct_eq(&(-self));
+
+        let r_prime = &constants::SQRT_M1 * &r;
+        r.conditional_assign(&r_prime, flipped_sign_sqrt);
+
+        // Choose the nonnegative square root.
+        let r_is_negative = r.is_negative();
+        r.conditional_negate(r_is_negative);
+
+        CtOption::new(r, correct_sign_sqrt | flipped_sign_sqrt)
+    }
+
+    /// Determine if this `BackendFieldElement` is a square, i.e. if its
+    /// Legendre symbol is not \(-1\).
+    ///
+    /// # Return
+    ///
+    /// If `self` is zero or a nonzero square, return `Choice(1)`.
+    /// Otherwise, return `Choice(0)`.
+    ///
+    /// The Legendre symbol is computed with Euler's criterion, as
+    /// `self^((p-1)/2)`. On the R0 backend, the square root from the host
+    /// is used instead if a `risc0_hint` handler is installed, since
+    /// checking it takes three multiplications rather than a full
+    /// exponentiation.
+    #[cfg(feature = "hazmat")]
+    pub(crate) fn is_square(&self) -> Choice {
+        #[cfg(any(
+            curve25519_dalek_backend = "risc0",
+            curve25519_dalek_backend = "risc0-emulated"
+        ))]
+        if let Some((was_square, _)) =
+            BackendFieldElement::sqrt_ratio_i_hinted(self, &BackendFieldElement::ONE)
+        {
+            return was_square;
+        }
+
+        // (p-1)/2 = 4 * (p-5)/8 + 2
+        let legendre = &self.pow_p58().pow2k(2) * &self.square();
+        !legendre.ct_eq(&BackendFieldElement::MINUS_ONE)
+    }
+}
+
+// The public field element, exposed by the `hazmat` feature.
+#[cfg(feature = "hazmat")]
+mod hazmat {
+    use core::borrow::Borrow;
+    use core::fmt::Debug;
+    use core::iter::{Product, Sum};
+    use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    #[cfg(feature = "group")]
+    use {
+        group::ff::{Field, PrimeField},
+        rand_core::RngCore,
+    };
+
+    use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+    #[cfg(feature = "zeroize")]
+    use zeroize::Zeroize;
+
+    use super::BackendFieldElement;
+    #[cfg(feature = "group")]
+    use crate::constants;
+
+    /// An element of the field \\( \mathbb Z / (2\^{255} - 19)\\).
+    ///
+    /// Its representation depends on the backend, but its API does not.
+    #[derive(Copy, Clone)]
+    pub struct FieldElement(BackendFieldElement);
+
+    impl Debug for FieldElement {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            write!(f, "FieldElement({:?})", &self.to_bytes())
+        }
+    }
+
+    impl Default for FieldElement {
+        fn default() -> FieldElement {
+            FieldElement::ZERO
+        }
+    }
+
+    impl Eq for FieldElement {}
+
+    impl PartialEq for FieldElement {
+        fn eq(&self, other: &FieldElement) -> bool {
+            self.ct_eq(other).into()
+        }
+    }
+
+    impl ConstantTimeEq for FieldElement {
+        fn ct_eq(&self, other: &FieldElement) -> Choice {
+            self.0.ct_eq(&other.0)
+        }
+    }
+
+    impl ConditionallySelectable for FieldElement {
+        fn conditional_select(a: &FieldElement, b: &FieldElement, choice: Choice) -> FieldElement {
+            FieldElement(BackendFieldElement::conditional_select(&a.0, &b.0, choice))
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    impl Zeroize for FieldElement {
+        fn zeroize(&mut self) {
+            self.0.zeroize();
+        }
+    }
+
+    impl<'a, 'b> Add<&'b FieldElement> for &'a FieldElement {
+        type Output = FieldElement;
+        fn add(self, rhs: &'b FieldElement) -> FieldElement {
+            FieldElement(&self.0 + &rhs.0)
+        }
+    }
+
+    define_add_variants!(
+        LHS = FieldElement,
+        RHS = FieldElement,
+        Output = FieldElement
+    );
+
+    impl<'b> AddAssign<&'b FieldElement> for FieldElement {
+        fn add_assign(&mut self, rhs: &'b FieldElement) {
+            self.0 += &rhs.0;
+        }
+    }
+
+    define_add_assign_variants!(LHS = FieldElement, RHS = FieldElement);
+
+    impl<'a, 'b> Sub<&'b FieldElement> for &'a FieldElement {
+        type Output = FieldElement;
+        fn sub(self, rhs: &'b FieldElement) -> FieldElement {
+            FieldElement(&self.0 - &rhs.0)
+        }
+    }
+
+    define_sub_variants!(
+        LHS = FieldElement,
+        RHS = FieldElement,
+        Output = FieldElement
+    );
+
+    impl<'b> SubAssign<&'b FieldElement> for FieldElement {
+        fn sub_assign(&mut self, rhs: &'b FieldElement) {
+            self.0 -= &rhs.0;
+        }
+    }
+
+    define_sub_assign_variants!(LHS = FieldElement, RHS = FieldElement);
+
+    impl<'a, 'b> Mul<&'b FieldElement> for &'a FieldElement {
+        type Output = FieldElement;
+        fn mul(self, rhs: &'b FieldElement) -> FieldElement {
+            FieldElement(&self.0 * &rhs.0)
+        }
+    }
+
+    define_mul_variants!(
+        LHS = FieldElement,
+        RHS = FieldElement,
+        Output = FieldElement
+    );
+
+    impl<'b> MulAssign<&'b FieldElement> for FieldElement {
+        fn mul_assign(&mut self, rhs: &'b FieldElement) {
+            self.0 *= &rhs.0;
+        }
+    }
+
+    define_mul_assign_variants!(LHS = FieldElement, RHS = FieldElement);
+
+    impl<'a> Neg for &'a FieldElement {
+        type Output = FieldElement;
+        fn neg(self) -> FieldElement {
+            FieldElement(-&self.0)
+        }
+    }
+
+    impl Neg for FieldElement {
+        type Output = FieldElement;
+        fn neg(self) -> FieldElement {
+            -&self
+        }
+    }
+
+    impl<T> Product<T> for FieldElement
+    where
+        T: Borrow<FieldElement>,
+    {
+        fn product<I>(iter: I) -> Self
+        where
+            I: Iterator<Item = T>,
+        {
+            iter.fold(FieldElement::ONE, |acc, item| acc * item.borrow())
+        }
+    }
+
+    impl<T> Sum<T> for FieldElement
+    where
+        T: Borrow<FieldElement>,
+    {
+        fn sum<I>(iter: I) -> Self
+        where
+            I: Iterator<Item = T>,
+        {
+            iter.fold(FieldElement::ZERO, |acc, item| acc + item.borrow())
+        }
+    }
+
+    impl From<u64> for FieldElement {
+        fn from(x: u64) -> FieldElement {
+            let mut bytes = [0u8; 32];
+            bytes[..8].copy_from_slice(&x.to_le_bytes());
+            FieldElement::from_bytes(&bytes)
+        }
+    }
+
+    impl FieldElement {
+        /// The field element zero.
+        pub const ZERO: FieldElement = FieldElement(BackendFieldElement::ZERO);
+
+        /// The field element one.
+        pub const ONE: FieldElement = FieldElement(BackendFieldElement::ONE);
+
+        /// Load a `FieldElement` from the low 255 bits of a 256-bit
+        /// little-endian input, reducing it modulo \\(p\\).
+        ///
+        /// The high bit is ignored, as in the rest of the crate.
+        pub fn from_bytes(bytes: &[u8; 32]) -> FieldElement {
+            FieldElement(BackendFieldElement::from_bytes(bytes))
+        }
+
+        /// Encode this `FieldElement` as its canonical little-endian byte
+        /// representation.
+        pub fn to_bytes(&self) -> [u8; 32] {
+            self.0.as_bytes()
+        }
+
+        /// Determine if this `FieldElement` is negative, in the sense
+        /// used in the ed25519 paper: `x` is negative if the low bit of its
+        /// canonical encoding is set.
+        pub fn is_negative(&self) -> Choice {
+            self.0.is_negative()
+        }
+
+        /// Determine if this `FieldElement` is zero.
+        pub fn is_zero(&self) -> Choice {
+            self.0.is_zero()
+        }
+
+        /// Compute the inverse of this `FieldElement`, or zero if it is zero.
+        pub fn invert(&self) -> FieldElement {
+            FieldElement(self.0.invert())
+        }
+
+        /// Replace each of `inputs` with its inverse, using a single inversion.
+        ///
+        /// Zero inputs are left unchanged.
+        #[cfg(feature = "alloc")]
+        pub fn batch_invert(inputs: &mut [FieldElement]) {
+            let mut inner: Vec<_> = inputs.iter().map(|x| x.0).collect();
+            BackendFieldElement::batch_invert(&mut inner);
+            for (input, inverse) in inputs.iter_mut().zip(inner) {
+                input.0 = inverse;
+            }
+        }
+
+        /// Given `FieldElements` `u` and `v`, compute either `sqrt(u/v)`
+        /// or `sqrt(i*u/v)` in constant time.
+        ///
+        /// This function always returns the nonnegative square root.
+        ///
+        /// # Return
+        ///
+        /// - `(Choice(1), +sqrt(u/v))  ` if `v` is nonzero and `u/v` is square;
+        /// - `(Choice(1), zero)        ` if `u` is zero;
+        /// - `(Choice(0), zero)        ` if `v` is zero and `u` is nonzero;
+        /// - `(Choice(0), +sqrt(i*u/v))` if `u/v` is nonsquare (so `i*u/v` is square).
+        ///
+        pub fn sqrt_ratio_i(u: &FieldElement, v: &FieldElement) -> (Choice, FieldElement) {
+            let (was_square, root) = BackendFieldElement::sqrt_ratio_i(&u.0, &v.0);
+            (was_square, FieldElement(root))
+        }
+
+        /// Compute the nonnegative square root of this `FieldElement` in
+        /// constant time.
+        ///
+        /// # Return
+        ///
+        /// - `Some(+sqrt(self))` if `self` is a square, including zero;
+        /// - `None` if `self` is a nonsquare.
+        pub fn sqrt(&self) -> CtOption<FieldElement> {
+            let root = self.0.sqrt();
+            CtOption::new(
+                FieldElement(root.unwrap_or(BackendFieldElement::ZERO)),
+                root.is_some(),
+            )
+        }
+
+        /// Determine if this `FieldElement` is a square, i.e. if its
+        /// Legendre symbol is not \\(-1\\).
+        pub fn is_square(&self) -> Choice {
+            self.0.is_square()
+        }
+    }
+
+    #[cfg(feature = "group")]
+    impl Field for FieldElement {
+        const ZERO: Self = Self::ZERO;
+        const ONE: Self = Self::ONE;
+
+        fn random(mut rng: impl RngCore) -> Self {
+            // Sample 255-bit strings until one is canonical, which fails with
+            // probability 19/2^255.
+            loop {
+                let mut bytes = [0u8; 32];
+                rng.fill_bytes(&mut bytes);
+                bytes[31] &= 0x7f;
+                if let Some(fe) = Self::from_repr_vartime(bytes) {
+                    return fe;
+                }
+            }
+        }
+
+        fn square(&self) -> Self {
+            self * self
+        }
+
+        fn double(&self) -> Self {
+            self + self
+        }
+
+        fn invert(&self) -> CtOption<Self> {
+            CtOption::new(self.invert(), !self.is_zero())
+        }
+
+        fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+            // ROOT_OF_UNITY is SQRT_M1, so the nonsquare case of
+            // sqrt_ratio_i is exactly the one required by `ff`.
+            FieldElement::sqrt_ratio_i(num, div)
+        }
+
+        fn sqrt(&self) -> CtOption<Self> {
+            FieldElement::sqrt(self)
+        }
+    }
+
+    #[cfg(feature = "group")]
+    impl PrimeField for FieldElement {
+        type Repr = [u8; 32];
+
+        fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+            // `from_bytes` ignores the high bit and reduces, so the encoding is
+            // canonical exactly when it survives a round trip.
+            let candidate = FieldElement::from_bytes(&repr);
+            CtOption::new(candidate, candidate.to_bytes().ct_eq(&repr))
+        }
+
+        fn to_repr(&self) -> Self::Repr {
+            self.to_bytes()
+        }
+
+        fn is_odd(&self) -> Choice {
+            self.is_negative()
+        }
+
+        const MODULUS: &'static str =
+            "0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed";
+        const NUM_BITS: u32 = 255;
+        const CAPACITY: u32 = 254;
+
+        const TWO_INV: Self = FieldElement(constants::FIELD_TWO_INV);
+        const MULTIPLICATIVE_GENERATOR: Self =
+            FieldElement(constants::FIELD_MULTIPLICATIVE_GENERATOR);
+        const S: u32 = 2;
+        const ROOT_OF_UNITY: Self = FieldElement(constants::SQRT_M1);
+        const ROOT_OF_UNITY_INV: Self = FieldElement(constants::FIELD_ROOT_OF_UNITY_INV);
+        const DELTA: Self = FieldElement(constants::FIELD_DELTA);
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        /// Random element a of GF(2^255-19), from Sage
+        /// a = 1070314506888354081329385823235218444233221\
+        ///     2228051251926706380353716438957572
+        #[cfg(feature = "group")]
+        static A_BYTES: [u8; 32] = [
+            0x04, 0xfe, 0xdf, 0x98, 0xa7, 0xfa, 0x0a, 0x68, 0x84, 0x92, 0xbd, 0x59, 0x08, 0x07,
+            0xa7, 0x03, 0x9e, 0xd1, 0xf6, 0xf2, 0xe1, 0xd9, 0xe2, 0xa4, 0xa4, 0x51, 0x47, 0x36,
+            0xf3, 0xc3, 0xa9, 0x17,
+        ];
+
+        /// Byte representation of a**2
+        #[cfg(feature = "group")]
+        static ASQ_BYTES: [u8; 32] = [
+            0x75, 0x97, 0x24, 0x9e, 0xe6, 0x06, 0xfe, 0xab, 0x24, 0x04, 0x56, 0x68, 0x07, 0x91,
+            0x2d, 0x5d, 0x0b, 0x0f, 0x3f, 0x1c, 0xb2, 0x6e, 0xf2, 0xe2, 0x63, 0x9c, 0x12, 0xba,
+            0x73, 0x0b, 0xe3, 0x62,
+        ];
+
+        /// Byte representation of 1/a
+        #[cfg(feature = "group")]
+        static AINV_BYTES: [u8; 32] = [
+            0x96, 0x1b, 0xcd, 0x8d, 0x4d, 0x5e, 0xa2, 0x3a, 0xe9, 0x36, 0x37, 0x93, 0xdb, 0x7b,
+            0x4d, 0x70, 0xb8, 0x0d, 0xc0, 0x55, 0xd0, 0x4c, 0x1d, 0x7b, 0x90, 0x71, 0xd8, 0xe9,
+            0xb6, 0x18, 0xe6, 0x30,
+        ];
+
+        #[test]
+        fn wraps_backend_arithmetic() {
+            let a = FieldElement::from(3u64);
+            let b = FieldElement::from(5u64);
+            assert_eq!((a * b).0, &a.0 * &b.0);
+            assert_eq!((a - b).0, &a.0 - &b.0);
+            assert_eq!((-a).0, -&a.0);
+            assert_eq!(a.invert().0, a.0.invert());
+            assert_eq!(FieldElement::from(15u64).to_bytes(), (a * b).0.as_bytes());
+            assert_eq!(
+                [a, b].iter().sum::<FieldElement>(),
+                FieldElement::from(8u64)
+            );
+            assert_eq!([a, b].iter().product::<FieldElement>(), a * b);
+        }
+
+        #[cfg(feature = "group")]
+        #[test]
+        fn ff_constants() {
+            use group::ff::{Field, PrimeField};
+
+            let two = FieldElement::ONE.double();
+            assert_eq!(two * FieldElement::TWO_INV, FieldElement::ONE);
+
+            assert_eq!(
+                FieldElement::ROOT_OF_UNITY * FieldElement::ROOT_OF_UNITY_INV,
+                FieldElement::ONE,
+            );
+
+            // ROOT_OF_UNITY^{2^s} mod p == 1, but ROOT_OF_UNITY^{2^(s-1)} != 1
+            assert_eq!(
+                FieldElement::ROOT_OF_UNITY.pow([1u64 << FieldElement::S]),
+                FieldElement::ONE,
+            );
+            assert_eq!(
+                FieldElement::ROOT_OF_UNITY.pow([1u64 << (FieldElement::S - 1)]),
+                -FieldElement::ONE,
+            );
+
+            // The generator is a nonsquare, and ROOT_OF_UNITY = GENERATOR^t
+            // with t = (p - 1)/4 = 2^253 - 5
+            let t = [
+                0xffff_ffff_ffff_fffb,
+                0xffff_ffff_ffff_ffff,
+                0xffff_ffff_ffff_ffff,
+                0x1fff_ffff_ffff_ffff,
+            ];
+            assert!(bool::from(
+                FieldElement::MULTIPLICATIVE_GENERATOR.sqrt().is_none()
+            ));
+            assert_eq!(
+                FieldElement::MULTIPLICATIVE_GENERATOR.pow(t),
+                FieldElement::ROOT_OF_UNITY,
+            );
+
+            // DELTA^{t} mod p == 1
+            assert_eq!(FieldElement::DELTA.pow(t), FieldElement::ONE);
+            assert_eq!(
+                FieldElement::MULTIPLICATIVE_GENERATOR.pow([1u64 << FieldElement::S]),
+                FieldElement::DELTA,
+            );
+        }
+
+        #[cfg(feature = "group")]
+        #[test]
+        fn ff_impls() {
+            use group::ff::{Field, PrimeField};
+
+            let a = FieldElement::from_bytes(&A_BYTES);
+            let ainv = FieldElement::from_bytes(&AINV_BYTES);
+            let asq = FieldElement::from_bytes(&ASQ_BYTES);
+
+            assert!(bool::from(FieldElement::ZERO.is_even()));
+            assert!(bool::from(FieldElement::ONE.is_odd()));
+            assert!(bool::from(a.is_even()));
+
+            assert!(bool::from(Field::invert(&FieldElement::ZERO).is_none()));
+            assert_eq!(Field::invert(&a).unwrap(), ainv);
+
+            // We should get back either the positive or negative root.
+            assert!([a, -a].contains(&asq.sqrt().unwrap()));
+            assert_eq!(FieldElement::ZERO.sqrt().unwrap(), FieldElement::ZERO);
+
+            let (is_square, root) = FieldElement::sqrt_ratio(&asq, &a);
+            assert!(bool::from(is_square));
+            assert_eq!(root.square() * a, asq);
+            let (is_square, root) =
+                FieldElement::sqrt_ratio(&FieldElement::MULTIPLICATIVE_GENERATOR, &a);
+            assert!(!bool::from(is_square));
+            assert_eq!(
+                root.square() * a,
+                FieldElement::ROOT_OF_UNITY * FieldElement::MULTIPLICATIVE_GENERATOR,
+            );
+
+            assert_eq!(FieldElement::from_repr_vartime(a.to_repr()), Some(a));
+            assert_eq!(FieldElement::from_repr(a.to_repr()).unwrap(), a);
+            // p - 1 is canonical, p and anything with the high bit set are not
+            let mut p_minus_one = (-FieldElement::ONE).to_repr();
+            assert!(bool::from(FieldElement::from_repr(p_minus_one).is_some()));
+            p_minus_one[0] += 1;
+            assert!(bool::from(FieldElement::from_repr(p_minus_one).is_none()));
+            assert!(bool::from(FieldElement::from_repr([0xff; 32]).is_none()));
+
+            let mut rng = rand::thread_rng();
+            let x = FieldElement::random(&mut rng);
+            assert_eq!(FieldElement::from_repr(x.to_repr()).unwrap(), x);
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::field::*;
+    use super::BackendFieldElement as FieldElement;
+    use super::*;
     use subtle::ConditionallyNegatable;
 
     /// Random element a of GF(2^255-19), from Sage
@@ -435,6 +1028,35 @@ mod test {
         assert!(bool::from(!sqrt.is_negative()));
     }
 
+    #[test]
+    fn sqrt_ratio_random() {
+        use rand::RngCore;
+
+        let mut rng = rand::thread_rng();
+        let i = constants::SQRT_M1;
+        for _ in 0..100 {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            let u = FieldElement::from_bytes(&bytes);
+            rng.fill_bytes(&mut bytes);
+            let v = FieldElement::from_bytes(&bytes);
+
+            // u/v is square iff uv is, which Euler's criterion decides:
+            // (uv)^((p-1)/2) = ((uv)^((p-5)/8))^4 * (uv)^2.
+            let uv = &u * &v;
+            let is_square = &uv.pow_p58().pow2k(2) * &uv.square() == FieldElement::ONE;
+
+            let (choice, sqrt) = FieldElement::sqrt_ratio_i(&u, &v);
+            assert_eq!(bool::from(choice), is_square);
+            assert!(bool::from(!sqrt.is_negative()));
+            if is_square {
+                assert_eq!(&v * &sqrt.square(), u);
+            } else {
+                assert_eq!(&v * &sqrt.square(), &i * &u);
+            }
+        }
+    }
+
     #[test]
     fn a_p58_vs_ap58_constant() {
         let a = FieldElement::from_bytes(&A_BYTES);
@@ -497,6 +1119,28 @@ mod test {
         }
     }
 
+    #[test]
+    #[cfg(feature = "hazmat")]
+    fn sqrt_and_is_square() {
+        let a = FieldElement::from_bytes(&A_BYTES);
+        let asq = FieldElement::from_bytes(&ASQ_BYTES);
+
+        // The root is the nonnegative one of a and -a.
+        let root = asq.sqrt().unwrap();
+        assert!(bool::from(!root.is_negative()));
+        assert!(root == a || root == -&a);
+        assert!(bool::from(asq.is_square()));
+
+        assert_eq!(FieldElement::ZERO.sqrt().unwrap(), FieldElement::ZERO);
+        assert!(bool::from(FieldElement::ZERO.is_square()));
+        assert!(bool::from(FieldElement::MINUS_ONE.is_square()));
+
+        // 2 is a nonsquare, and so is 2 * a^2.
+        let two = &FieldElement::ONE + &FieldElement::ONE;
+        assert!(bool::from(two.sqrt().is_none()));
+        assert!(bool::from(!(&two * &asq).is_square()));
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn batch_invert_empty() {
@@ -0,0 +1,675 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Hashing to curve25519, edwards25519 and ristretto255, as specified in
+//! [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+//!
+//! This module exposes the two `expand_message` functions of RFC 9380 §5.3, which turn a message
+//! and a domain separation tag into uniformly random bytes, for use by protocols that need them
+//! directly. The hash-to-curve suites built on top of them are exposed as
+//! [`EdwardsPoint::hash_to_curve`], [`MontgomeryPoint::hash_to_curve`] and
+//! [`RistrettoPoint::hash_to_group`], along with their `encode_to_curve` variants.
+//!
+//! [`MontgomeryPoint::hash_to_curve`]: crate::montgomery::MontgomeryPoint::hash_to_curve
+//! [`RistrettoPoint::hash_to_group`]: crate::ristretto::RistrettoPoint::hash_to_group
+
+use digest::crypto_common::{Block, BlockSizeUser};
+use digest::generic_array::typenum::U64;
+use digest::{Digest, ExtendableOutput, Output, Update, XofReader};
+use subtle::ConditionallySelectable;
+
+use crate::constants::ED25519_SQRTAM2;
+use crate::edwards::EdwardsPoint;
+use crate::field::BackendFieldElement as FieldElement;
+use crate::montgomery::elligator_encode_uv;
+
+/// The number of bytes hashed into each field element, \\(L = \lceil (255 + 128) / 8 \rceil\\)
+/// for a security level of 128 bits.
+const L: usize = 48;
+
+/// The tag prepended to domain separation tags longer than 255 bytes before hashing them down,
+/// as described in RFC 9380 §5.3.3.
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Fill `out` with `expand_message_xmd(msg, dst, out.len())` from RFC 9380 §5.3.1, where `msg`
+/// is the concatenation of the slices in `msgs`.
+///
+/// `D` may be any hash function with an output of at least \(2k\) bits for a target security
+/// level of \(k\) bits, such as `Sha512`. Domain separation tags longer than 255 bytes are hashed
+/// down as described in RFC 9380 §5.3.3.
+///
+/// # Panics
+///
+/// If `out` is longer than 255 times the output size of `D`.
+///
+/// # Example
+///
+/// ```
+/// # use curve25519_dalek::hash_to_curve::expand_message_xmd;
+/// use sha2::Sha512;
+///
+/// let mut uniform_bytes = [0u8; 32];
+/// expand_message_xmd::<Sha512>(&[b"abc"], b"QUUX-V01-CS02-with-expander-SHA512-256", &mut uniform_bytes);
+/// assert_eq!(
+///     hex::encode(uniform_bytes),
+///     "0da749f12fbe5483eb066a5f595055679b976e93abe9be6f0f6318bce7aca8dc"
+/// );
+/// ```
+pub fn expand_message_xmd<D>(msgs: &[&[u8]], dst: &[u8], out: &mut [u8])
+where
+    D: Digest + BlockSizeUser + Default,
+{
+    let b_in_bytes = <D as Digest>::output_size();
+    let ell = (out.len() + b_in_bytes - 1) / b_in_bytes;
+    assert!(ell <= 255, "expand_message_xmd output is too long");
+
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = D::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize();
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+    let mut hash = D::new().chain_update(Block::<D>::default());
+    for msg in msgs {
+        hash.update(msg);
+    }
+    let b_0 = hash
+        .chain_update((out.len() as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime), except that b_1 hashes b_0
+    // itself. Starting from an all-zero b_(i - 1) covers both cases.
+    let mut b_i = Output::<D>::default();
+    for (i, chunk) in out.chunks_mut(b_in_bytes).enumerate() {
+        let mut input = b_0.clone();
+        for (x, b) in input.iter_mut().zip(b_i.iter()) {
+            *x ^= b;
+        }
+        b_i = D::new()
+            .chain_update(input)
+            .chain_update([(i + 1) as u8])
+            .chain_update(dst)
+            .chain_update(dst_len)
+            .finalize();
+        chunk.copy_from_slice(&b_i[..chunk.len()]);
+    }
+}
+
+/// Fill `out` with `expand_message_xof(msg, dst, out.len())` from RFC 9380 §5.3.2, where `msg`
+/// is the concatenation of the slices in `msgs`.
+///
+/// `X` may be any extendable-output function, such as `Shake128`, and `k` is its target security
+/// level in bits. The security level is only used to hash down domain separation tags longer
+/// than 255 bytes, as described in RFC 9380 §5.3.3.
+///
+/// # Panics
+///
+/// If `out` is longer than \(2\^{16} - 1\) bytes, or if `dst` is longer than 255 bytes and
+/// `k` is greater than 256.
+pub fn expand_message_xof<X>(msgs: &[&[u8]], dst: &[u8], k: usize, out: &mut [u8])
+where
+    X: ExtendableOutput + Update + Default,
+{
+    assert!(
+        out.len() <= u16::MAX as usize,
+        "expand_message_xof output is too long"
+    );
+
+    // Enough room for a hashed down tag at security levels up to 256 bits.
+    let mut oversize_dst = [0u8; 64];
+    let dst = if dst.len() > 255 {
+        assert!(
+            k <= 256,
+            "expand_message_xof security level is too high to hash down the tag"
+        );
+        let oversize_dst = &mut oversize_dst[..(2 * k + 7) / 8];
+        let mut hash = X::default();
+        hash.update(OVERSIZE_DST_PREFIX);
+        hash.update(dst);
+        hash.finalize_xof().read(oversize_dst);
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+
+    // uniform_bytes = H(msg || I2OSP(len_in_bytes, 2) || DST_prime, len_in_bytes)
+    let mut hash = X::default();
+    for msg in msgs {
+        hash.update(msg);
+    }
+    hash.update(&(out.len() as u16).to_be_bytes());
+    hash.update(dst);
+    hash.update(&[dst.len() as u8]);
+    hash.finalize_xof().read(out);
+}
+
+/// Fill `out` with `hash_to_field(msg, out.len())` for the field \\( \mathbb Z / (2\^{255} - 19)
+/// \\), using `expand_message_xmd`.
+///
+/// # Panics
+///
+/// If `out` has more than two elements, which is all that the hash-to-curve suites need.
+pub(crate) fn hash_to_field<D>(msgs: &[&[u8]], dst: &[u8], out: &mut [FieldElement])
+where
+    D: Digest<OutputSize = U64> + BlockSizeUser + Default,
+{
+    let mut uniform_bytes = [0u8; 2 * L];
+    let uniform_bytes = &mut uniform_bytes[..out.len() * L];
+    expand_message_xmd::<D>(msgs, dst, uniform_bytes);
+
+    for (fe, bytes) in out.iter_mut().zip(uniform_bytes.chunks(L)) {
+        *fe = from_be_bytes_wide(bytes);
+    }
+}
+
+/// Reduce a 48-byte big-endian integer modulo \\(p\\).
+fn from_be_bytes_wide(bytes: &[u8]) -> FieldElement {
+    // Split the integer as hi * 2^192 + lo, where both halves are below 2^192 < p and can be
+    // loaded as field elements without reduction.
+    let mut lo = [0u8; 32];
+    let mut hi = [0u8; 32];
+    for i in 0..24 {
+        lo[i] = bytes[L - 1 - i];
+        hi[i] = bytes[L / 2 - 1 - i];
+    }
+    let mut two_pow_192 = [0u8; 32];
+    two_pow_192[24] = 1;
+
+    let hi = &FieldElement::from_bytes(&hi) * &FieldElement::from_bytes(&two_pow_192);
+    &hi + &FieldElement::from_bytes(&lo)
+}
+
+/// The Elligator 2 `map_to_curve` for edwards25519 from RFC 9380 §6.8.2.
+///
+/// This maps `r` to Curve25519 with [`elligator_encode_uv`], then applies the rational map
+/// \\((x, y) = (\sqrt{-486664} \cdot u / v, (u - 1) / (u + 1))\\). The exceptional points with
+/// \\(v = 0\\) or \\(u = -1\\) are sent to the identity.
+pub(crate) fn map_to_curve(r: &FieldElement) -> EdwardsPoint {
+    let (u, v) = elligator_encode_uv(r);
+
+    let one = FieldElement::ONE;
+    let mut xn = &ED25519_SQRTAM2 * &u;
+    let mut xd = v;
+    let mut yn = &u - &one;
+    let mut yd = &u + &one;
+
+    let exceptional = (&xd * &yd).is_zero();
+    xn.conditional_assign(&FieldElement::ZERO, exceptional);
+    xd.conditional_assign(&one, exceptional);
+    yn.conditional_assign(&one, exceptional);
+    yd.conditional_assign(&one, exceptional);
+
+    // (xn / xd, yn / yd) in extended coordinates.
+    EdwardsPoint {
+        X: &xn * &yd,
+        Y: &yn * &xd,
+        Z: &xd * &yd,
+        T: &xn * &yn,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::edwards::CompressedEdwardsY;
+    use crate::montgomery::MontgomeryPoint;
+    use crate::ristretto::RistrettoPoint;
+    use crate::traits::{IsIdentity, ValidityCheck};
+    use sha2::{Sha256, Sha512};
+    use sha3::Shake128;
+
+    /// Test vectors for `expand_message_xmd` with SHA-512, from RFC 9380 Appendix K.3, as
+    /// (msg, len_in_bytes, uniform_bytes).
+    const EXPAND_MESSAGE_XMD_SHA512: &[(&[u8], usize, &str)] = &[
+        (
+            b"",
+            0x20,
+            "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba",
+        ),
+        (
+            b"abc",
+            0x20,
+            "0da749f12fbe5483eb066a5f595055679b976e93abe9be6f0f6318bce7aca8dc",
+        ),
+        (
+            b"abcdef0123456789",
+            0x20,
+            "087e45a86e2939ee8b91100af1583c4938e0f5fc6c9db4b107b83346bc967f58",
+        ),
+        (
+            b"",
+            0x80,
+            "41b037d1734a5f8df225dd8c7de38f851efdb45c372887be655212d07251b921\
+             b052b62eaed99b46f72f2ef4cc96bfaf254ebbbec091e1a3b9e4fb5e5b619d2e\
+             0c5414800a1d882b62bb5cd1778f098b8eb6cb399d5d9d18f5d5842cf5d13d7e\
+             b00a7cff859b605da678b318bd0e65ebff70bec88c753b159a805d2c89c55961",
+        ),
+        (
+            b"abc",
+            0x80,
+            "7f1dddd13c08b543f2e2037b14cefb255b44c83cc397c1786d975653e36a6b11\
+             bdd7732d8b38adb4a0edc26a0cef4bb45217135456e58fbca1703cd6032cb134\
+             7ee720b87972d63fbf232587043ed2901bce7f22610c0419751c065922b48843\
+             1851041310ad659e4b23520e1772ab29dcdeb2002222a363f0c2b1c972b3efe1",
+        ),
+    ];
+
+    #[test]
+    fn expand_message_xmd_sha512() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        for (msg, len, expected) in EXPAND_MESSAGE_XMD_SHA512 {
+            let mut out = [0u8; 0x80];
+            expand_message_xmd::<Sha512>(&[msg], dst, &mut out[..*len]);
+            assert_eq!(hex::encode(&out[..*len]), *expected);
+        }
+    }
+
+    #[test]
+    fn expand_message_xmd_split_message() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        let mut whole = [0u8; 0x20];
+        let mut split = [0u8; 0x20];
+        expand_message_xmd::<Sha512>(&[b"abcdef0123456789"], dst, &mut whole);
+        expand_message_xmd::<Sha512>(&[b"abcdef", b"", b"0123456789"], dst, &mut split);
+        assert_eq!(whole, split);
+    }
+
+    #[test]
+    fn expand_message_xmd_oversize_dst() {
+        let long_dst = [0x41u8; 256];
+        let hashed_dst = Sha512::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(long_dst)
+            .finalize();
+        let mut long = [0u8; 0x40];
+        let mut hashed = [0u8; 0x40];
+        expand_message_xmd::<Sha512>(&[b"abc"], &long_dst, &mut long);
+        expand_message_xmd::<Sha512>(&[b"abc"], &hashed_dst, &mut hashed);
+        assert_eq!(long, hashed);
+    }
+
+    /// Test vectors for `expand_message_xmd` with SHA-256, from RFC 9380 Appendix K.1, as
+    /// (msg, len_in_bytes, uniform_bytes).
+    const EXPAND_MESSAGE_XMD_SHA256: &[(&[u8], usize, &str)] = &[
+        (
+            b"",
+            0x20,
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+        ),
+        (
+            b"abc",
+            0x20,
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+        ),
+        (
+            b"",
+            0x80,
+            "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+             e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+             eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+             c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+        ),
+    ];
+
+    #[test]
+    fn expand_message_xmd_sha256() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        for (msg, len, expected) in EXPAND_MESSAGE_XMD_SHA256 {
+            let mut out = [0u8; 0x80];
+            expand_message_xmd::<Sha256>(&[msg], dst, &mut out[..*len]);
+            assert_eq!(hex::encode(&out[..*len]), *expected);
+        }
+    }
+
+    /// Test vectors for `expand_message_xof` with SHAKE128, from RFC 9380 Appendix K.4, as
+    /// (msg, len_in_bytes, uniform_bytes).
+    const EXPAND_MESSAGE_XOF_SHAKE128: &[(&[u8], usize, &str)] = &[
+        (
+            b"",
+            0x20,
+            "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2",
+        ),
+        (
+            b"abc",
+            0x20,
+            "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468",
+        ),
+        (
+            b"",
+            0x80,
+            "7314ff1a155a2fb99a0171dc71b89ab6e3b2b7d59e38e64419b8b6294d03ffee\
+             42491f11370261f436220ef787f8f76f5b26bdcd850071920ce023f3ac468477\
+             44f4612b8714db8f5db83205b2e625d95afd7d7b4d3094d3bdde815f52850bb4\
+             1ead9822e08f22cf41d615a303b0d9dde73263c049a7b9898208003a739a2e57",
+        ),
+        (
+            b"abc",
+            0x80,
+            "c952f0c8e529ca8824acc6a4cab0e782fc3648c563ddb00da7399f2ae35654f4\
+             860ec671db2356ba7baa55a34a9d7f79197b60ddae6e64768a37d699a7832349\
+             6db3878c8d64d909d0f8a7de4927dcab0d3dbbc26cb20a49eceb0530b431cdf4\
+             7bc8c0fa3e0d88f53b318b6739fbed7d7634974f1b5c386d6230c76260d5337a",
+        ),
+    ];
+
+    #[test]
+    fn expand_message_xof_shake128() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHAKE128";
+        for (msg, len, expected) in EXPAND_MESSAGE_XOF_SHAKE128 {
+            let mut out = [0u8; 0x80];
+            expand_message_xof::<Shake128>(&[msg], dst, 128, &mut out[..*len]);
+            assert_eq!(hex::encode(&out[..*len]), *expected);
+        }
+    }
+
+    #[test]
+    fn expand_message_xof_oversize_dst() {
+        let long_dst = [0x41u8; 256];
+        let mut hashed_dst = [0u8; 32];
+        let mut hash = Shake128::default();
+        hash.update(OVERSIZE_DST_PREFIX);
+        hash.update(&long_dst);
+        hash.finalize_xof().read(&mut hashed_dst);
+
+        let mut long = [0u8; 0x40];
+        let mut hashed = [0u8; 0x40];
+        let mut split = [0u8; 0x40];
+        expand_message_xof::<Shake128>(&[b"abc"], &long_dst, 128, &mut long);
+        expand_message_xof::<Shake128>(&[b"abc"], &hashed_dst, 128, &mut hashed);
+        expand_message_xof::<Shake128>(&[b"a", b"bc"], &long_dst, 128, &mut split);
+        assert_eq!(long, hashed);
+        assert_eq!(long, split);
+    }
+
+    #[test]
+    #[should_panic(expected = "security level is too high")]
+    fn expand_message_xof_oversize_dst_high_security_level() {
+        let mut out = [0u8; 0x40];
+        expand_message_xof::<Shake128>(&[b"abc"], &[0x41u8; 256], 264, &mut out);
+    }
+
+    /// Regression pins for `ristretto255_XMD:SHA-512_R255MAP_RO_`, as the encoding of the output
+    /// for each of `MESSAGES`.
+    ///
+    /// These are **not** external test vectors: neither RFC 9380 nor RFC 9496 publishes any for
+    /// this suite, so they were produced by this implementation and only detect changes to it.
+    /// Correctness rests on its two parts, `expand_message_xmd` and `from_uniform_bytes`, which
+    /// are checked against the RFC 9380 and RFC 9496 vectors respectively.
+    const RISTRETTO255_RO_REGRESSION: [&str; 5] = [
+        "bed61e1ee1966329962880e236dfdc83afd52fd1ce116f64fb806f1e8acea926",
+        "627b997b104ee62543358e22576c75a98dff9dc5f348d5ab228689735d77b258",
+        "90348aa2cced1007a4cd1b4cef9c1105d09a4b491766dad0de7f6ea39423ea32",
+        "a83367182a9928a7188576376291816ccab9e8293007401f3db8f1cbf1fc6934",
+        "eacd8dcc6376d75f11c2e8126385bfb9aecd91b8482b6226835c097a6b503d23",
+    ];
+
+    #[test]
+    fn ristretto255_hash_to_group_regression() {
+        let dst = b"QUUX-V01-CS02-with-ristretto255_XMD:SHA-512_R255MAP_RO_";
+        for (msg, expected) in MESSAGES.iter().zip(RISTRETTO255_RO_REGRESSION.iter()) {
+            let point = RistrettoPoint::hash_to_group::<Sha512>(&[msg], dst);
+            assert_eq!(hex::encode(point.compress().as_bytes()), *expected);
+        }
+    }
+
+    #[test]
+    fn from_be_bytes_wide_reduces() {
+        // p and p + 1, as 48-byte big-endian integers.
+        let mut p = [0u8; L];
+        p[L - 32..].copy_from_slice(
+            &hex::decode("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed")
+                .unwrap(),
+        );
+        assert_eq!(from_be_bytes_wide(&p), FieldElement::ZERO);
+        p[L - 1] += 1;
+        assert_eq!(from_be_bytes_wide(&p), FieldElement::ONE);
+
+        // 2^384 - 1 = 2^129 * 19 - 1 (mod p)
+        assert_eq!(
+            hex::encode(from_be_bytes_wide(&[0xff; L]).as_bytes()),
+            "ffffffffffffffffffffffffffffffff25000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn map_to_curve_exceptional_point() {
+        // r = 0 is mapped to the 2-torsion point (0, 0) of Curve25519, whose v-coordinate is
+        // zero, and so to the identity.
+        let point = map_to_curve(&FieldElement::ZERO);
+        assert!(point.is_valid());
+        assert!(point.is_identity());
+    }
+
+    /// Check a point against the affine coordinates given in big-endian hex by RFC 9380.
+    fn assert_point_eq(point: &EdwardsPoint, x: &str, y: &str) {
+        let x = hex::decode(x).unwrap();
+        let mut bytes: [u8; 32] = hex::decode(y).unwrap().try_into().unwrap();
+        bytes.reverse();
+        bytes[31] |= (x[31] & 1) << 7;
+        assert!(point.is_valid());
+        assert_eq!(point.compress(), CompressedEdwardsY(bytes));
+    }
+
+    const MESSAGES: [&[u8]; 5] = [
+        b"",
+        b"abc",
+        b"abcdef0123456789",
+        b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+        b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+    ];
+
+    /// Test vectors for `edwards25519_XMD:SHA-512_ELL2_RO_` from RFC 9380 Appendix J.5.1, as
+    /// (P.x, P.y, u[0], u[1]) for each of `MESSAGES`.
+    const EDWARDS25519_RO: [[&str; 4]; 5] = [
+        [
+            "3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6",
+            "09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21",
+            "03fef4813c8cb5f98c6eef88fae174e6e7d5380de2b007799ac7ee712d203f3a",
+            "780bdddd137290c8f589dc687795aafae35f6b674668d92bf92ae793e6a60c75",
+        ],
+        [
+            "608040b42285cc0d72cbb3985c6b04c935370c7361f4b7fbdb1ae7f8c1a8ecad",
+            "1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531",
+            "5081955c4141e4e7d02ec0e36becffaa1934df4d7a270f70679c78f9bd57c227",
+            "005bdc17a9b378b6272573a31b04361f21c371b256252ae5463119aa0b925b76",
+        ],
+        [
+            "6d7fabf47a2dc03fe7d47f7dddd21082c5fb8f86743cd020f3fb147d57161472",
+            "53060a3d140e7fbcda641ed3cf42c88a75411e648a1add71217f70ea8ec561a6",
+            "285ebaa3be701b79871bcb6e225ecc9b0b32dff2d60424b4c50642636a78d5b3",
+            "2e253e6a0ef658fedb8e4bd6a62d1544fd6547922acb3598ec6b369760b81b31",
+        ],
+        [
+            "5fb0b92acedd16f3bcb0ef83f5c7b7a9466b5f1e0d8d217421878ea3686f8524",
+            "2eca15e355fcfa39d2982f67ddb0eea138e2994f5956ed37b7f72eea5e89d2f7",
+            "4fedd25431c41f2a606952e2945ef5e3ac905a42cf64b8b4d4a83c533bf321af",
+            "02f20716a5801b843987097a8276b6d869295b2e11253751ca72c109d37485a9",
+        ],
+        [
+            "0efcfde5898a839b00997fbe40d2ebe950bc81181afbd5cd6b9618aa336c1e8c",
+            "6dc2fc04f266c5c27f236a80b14f92ccd051ef1ff027f26a07f8c0f327d8f995",
+            "6e34e04a5106e9bd59f64aba49601bf09d23b27f7b594e56d5de06df4a4ea33b",
+            "1c1c2cb59fc053f44b86c5d5eb8c1954b64976d0302d3729ff66e84068f5fd96",
+        ],
+    ];
+
+    /// Test vectors for `edwards25519_XMD:SHA-512_ELL2_NU_` from RFC 9380 Appendix J.5.2, as
+    /// (P.x, P.y, u[0]) for each of `MESSAGES`.
+    const EDWARDS25519_NU: [[&str; 3]; 5] = [
+        [
+            "1ff2b70ecf862799e11b7ae744e3489aa058ce805dd323a936375a84695e76da",
+            "222e314d04a4d5725e9f2aff9fb2a6b69ef375a1214eb19021ceab2d687f0f9b",
+            "7f3e7fb9428103ad7f52db32f9df32505d7b427d894c5093f7a0f0374a30641d",
+        ],
+        [
+            "5f13cc69c891d86927eb37bd4afc6672360007c63f68a33ab423a3aa040fd2a8",
+            "67732d50f9a26f73111dd1ed5dba225614e538599db58ba30aaea1f5c827fa42",
+            "09cfa30ad79bd59456594a0f5d3a76f6b71c6787b04de98be5cd201a556e253b",
+        ],
+        [
+            "1dd2fefce934ecfd7aae6ec998de088d7dd03316aa1847198aecf699ba6613f1",
+            "2f8a6c24dd1adde73909cada6a4a137577b0f179d336685c4a955a0a8e1a86fb",
+            "475ccff99225ef90d78cc9338e9f6a6bb7b17607c0c4428937de75d33edba941",
+        ],
+        [
+            "35fbdc5143e8a97afd3096f2b843e07df72e15bfca2eaf6879bf97c5d3362f73",
+            "2af6ff6ef5ebba128b0774f4296cb4c2279a074658b083b8dcca91f57a603450",
+            "049a1c8bd51bcb2aec339f387d1ff51428b88d0763a91bcdf6929814ac95d03d",
+        ],
+        [
+            "6e5e1f37e99345887fc12111575fc1c3e36df4b289b8759d23af14d774b66bff",
+            "2c90c3d39eb18ff291d33441b35f3262cdd307162cc97c31bfcc7a4245891a37",
+            "3cb0178a8137cefa5b79a3a57c858d7eeeaa787b2781be4a362a2f0750d24fa0",
+        ],
+    ];
+
+    /// Test vectors for `curve25519_XMD:SHA-512_ELL2_RO_` from RFC 9380 Appendix J.4.1, as
+    /// (P.x, P.y, u[0], u[1]) for each of `MESSAGES`.
+    const CURVE25519_RO: [[&str; 4]; 5] = [
+        [
+            "2de3780abb67e861289f5749d16d3e217ffa722192d16bbd9d1bfb9d112b98c0",
+            "3b5dc2a498941a1033d176567d457845637554a2fe7a3507d21abd1c1bd6e878",
+            "005fe8a7b8fef0a16c105e6cadf5a6740b3365e18692a9c05bfbb4d97f645a6a",
+            "1347edbec6a2b5d8c02e058819819bee177077c9d10a4ce165aab0fd0252261a",
+        ],
+        [
+            "2b4419f1f2d48f5872de692b0aca72cc7b0a60915dd70bde432e826b6abc526d",
+            "1b8235f255a268f0a6fa8763e97eb3d22d149343d495da1160eff9703f2d07dd",
+            "49bed021c7a3748f09fa8cdfcac044089f7829d3531066ac9e74e0994e05bc7d",
+            "5c36525b663e63389d886105cee7ed712325d5a97e60e140aba7e2ce5ae851b6",
+        ],
+        [
+            "68ca1ea5a6acf4e9956daa101709b1eee6c1bb0df1de3b90d4602382a104c036",
+            "2a375b656207123d10766e68b938b1812a4a6625ff83cb8d5e86f58a4be08353",
+            "6412b7485ba26d3d1b6c290a8e1435b2959f03721874939b21782df17323d160",
+            "24c7b46c1c6d9a21d32f5707be1380ab82db1054fde82865d5c9e3d968f287b2",
+        ],
+        [
+            "096e9c8bae6c06b554c1ee69383bb0e82267e064236b3a30608d4ed20b73ac5a",
+            "1eb5a62612cafb32b16c3329794645b5b948d9f8ffe501d4e26b073fef6de355",
+            "5e123990f11bbb5586613ffabdb58d47f64bb5f2fa115f8ea8df0188e0c9e1b5",
+            "5e8553eb00438a0bb1e7faa59dec6d8087f9c8011e5fb8ed9df31cb6c0d4ac19",
+        ],
+        [
+            "1bc61845a138e912f047b5e70ba9606ba2a447a4dade024c8ef3dd42b7bbc5fe",
+            "623d05e47b70e25f7f1d51dda6d7c23c9a18ce015fe3548df596ea9e38c69bf1",
+            "20f481e85da7a3bf60ac0fb11ed1d0558fc6f941b3ac5469aa8b56ec883d6d7d",
+            "017d57fd257e9a78913999a23b52ca988157a81b09c5442501d07fed20869465",
+        ],
+    ];
+
+    /// Test vectors for `curve25519_XMD:SHA-512_ELL2_NU_` from RFC 9380 Appendix J.4.2, as
+    /// (P.x, P.y, u[0]) for each of `MESSAGES`.
+    const CURVE25519_NU: [[&str; 3]; 5] = [
+        [
+            "1bb913f0c9daefa0b3375378ffa534bda5526c97391952a7789eb976edfe4d08",
+            "4548368f4f983243e747b62a600840ae7c1dab5c723991f85d3a9768479f3ec4",
+            "608d892b641f0328523802a6603427c26e55e6f27e71a91a478148d45b5093cd",
+        ],
+        [
+            "7c22950b7d900fa866334262fcaea47a441a578df43b894b4625c9b450f9a026",
+            "5547bc00e4c09685dcbc6cb6765288b386d8bdcb595fa5a6e3969e08097f0541",
+            "46f5b22494bfeaa7f232cc8d054be68561af50230234d7d1d63d1d9abeca8da5",
+        ],
+        [
+            "31ad08a8b0deeb2a4d8b0206ca25f567ab4e042746f792f4b7973f3ae2096c52",
+            "405070c28e78b4fa269427c82827261991b9718bd6c6e95d627d701a53c30db1",
+            "235fe40c443766ce7e18111c33862d66c3b33267efa50d50f9e8e5d252a40aaa",
+        ],
+        [
+            "027877759d155b1997d0d84683a313eb78bdb493271d935b622900459d52ceaa",
+            "54d691731a53baa30707f4a87121d5169fb5d587d70fb0292b5830dedbec4c18",
+            "001e92a544463bda9bd04ddbe3d6eed248f82de32f522669efc5ddce95f46f5b",
+        ],
+        [
+            "5fd892c0958d1a75f54c3182a18d286efab784e774d1e017ba2fb252998b5dc1",
+            "750af3c66101737423a4519ac792fb93337bd74ee751f19da4cf1e94f4d6d0b8",
+            "1a68a1af9f663592291af987203393f707305c7bac9c8d63d6a729bdc553dc19",
+        ],
+    ];
+
+    /// Check a field element against the big-endian hex given by RFC 9380.
+    fn assert_field_element_eq(fe: &FieldElement, expected: &str) {
+        let mut bytes = fe.as_bytes();
+        bytes.reverse();
+        assert_eq!(hex::encode(bytes), expected);
+    }
+
+    #[test]
+    fn edwards25519_xmd_sha512_ell2_ro() {
+        let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+        for (msg, [x, y, u0, u1]) in MESSAGES.iter().zip(EDWARDS25519_RO.iter()) {
+            let mut u = [FieldElement::ZERO; 2];
+            hash_to_field::<Sha512>(&[msg], dst, &mut u);
+            assert_field_element_eq(&u[0], u0);
+            assert_field_element_eq(&u[1], u1);
+
+            let point = EdwardsPoint::hash_to_curve::<Sha512>(msg, dst);
+            assert_point_eq(&point, x, y);
+        }
+    }
+
+    #[test]
+    fn edwards25519_xmd_sha512_ell2_nu() {
+        let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_";
+        for (msg, [x, y, u0]) in MESSAGES.iter().zip(EDWARDS25519_NU.iter()) {
+            let mut u = [FieldElement::ZERO; 1];
+            hash_to_field::<Sha512>(&[msg], dst, &mut u);
+            assert_field_element_eq(&u[0], u0);
+
+            let point = EdwardsPoint::encode_to_curve::<Sha512>(msg, dst);
+            assert_point_eq(&point, x, y);
+        }
+    }
+
+    /// Check a Montgomery point, given with the sign of the corresponding Edwards
+    /// \\(x\\)-coordinate, against the affine coordinates given in big-endian hex by RFC 9380.
+    fn assert_montgomery_point_eq(point: &(MontgomeryPoint, u8), u: &str, v: &str) {
+        let (point, sign) = point;
+        let edwards = point.to_edwards(*sign).unwrap();
+        let x = &edwards.X * &edwards.Z.invert();
+        let u_fe = FieldElement::from_bytes(&point.0);
+        assert_field_element_eq(&u_fe, u);
+        // v = sqrt(-486664) * u / x
+        assert_field_element_eq(&(&(&ED25519_SQRTAM2 * &u_fe) * &x.invert()), v);
+    }
+
+    #[test]
+    fn curve25519_xmd_sha512_ell2_ro() {
+        let dst = b"QUUX-V01-CS02-with-curve25519_XMD:SHA-512_ELL2_RO_";
+        for (msg, [x, y, u0, u1]) in MESSAGES.iter().zip(CURVE25519_RO.iter()) {
+            let mut u = [FieldElement::ZERO; 2];
+            hash_to_field::<Sha512>(&[msg], dst, &mut u);
+            assert_field_element_eq(&u[0], u0);
+            assert_field_element_eq(&u[1], u1);
+
+            let point = MontgomeryPoint::hash_to_curve::<Sha512>(msg, dst);
+            assert_montgomery_point_eq(&point, x, y);
+        }
+    }
+
+    #[test]
+    fn curve25519_xmd_sha512_ell2_nu() {
+        let dst = b"QUUX-V01-CS02-with-curve25519_XMD:SHA-512_ELL2_NU_";
+        for (msg, [x, y, u0]) in MESSAGES.iter().zip(CURVE25519_NU.iter()) {
+            let mut u = [FieldElement::ZERO; 1];
+            hash_to_field::<Sha512>(&[msg], dst, &mut u);
+            assert_field_element_eq(&u[0], u0);
+
+            let point = MontgomeryPoint::encode_to_curve::<Sha512>(msg, dst);
+            assert_montgomery_point_eq(&point, x, y);
+        }
+    }
+}
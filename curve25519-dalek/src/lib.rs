@@ -86,11 +86,24 @@ pub mod constants;
 // External (and internal) traits.
 pub mod traits;
 
+// Hex and decimal strings for scalars and compressed points
+pub mod encoding;
+
+// Hashing to the curve as specified in RFC 9380
+#[cfg(feature = "digest")]
+pub mod hash_to_curve;
+
+// Untrusted answers from the host for the RISC Zero backend
+pub mod risc0_hint;
+
 //------------------------------------------------------------------------
 // curve25519-dalek internal modules
 //------------------------------------------------------------------------
 
 // Finite field arithmetic mod p = 2^255 - 19
+#[cfg(feature = "hazmat")]
+pub mod field;
+#[cfg(not(feature = "hazmat"))]
 pub(crate) mod field;
 
 // Arithmetic backends (using u32, u64, etc) live here
@@ -106,6 +119,23 @@ pub use crate::{
     edwards::EdwardsPoint, montgomery::MontgomeryPoint, ristretto::RistrettoPoint, scalar::Scalar,
 };
 
+// Precompile call accounting for the RISC Zero backend
+#[cfg(all(
+    feature = "risc0-op-counters",
+    any(
+        curve25519_dalek_backend = "risc0",
+        curve25519_dalek_backend = "risc0-emulated"
+    )
+))]
+pub use crate::backend::serial::risc0::counters as risc0_op_counters;
+
+// Detection of an uncooperative host by the RISC Zero backend
+#[cfg(any(
+    curve25519_dalek_backend = "risc0",
+    curve25519_dalek_backend = "risc0-emulated"
+))]
+pub use crate::backend::serial::risc0::fault as risc0_fault;
+
 // Build time diagnostics for validation
 #[cfg(curve25519_dalek_diagnostics = "build")]
 mod diagnostics;
@@ -121,3 +121,32 @@ macro_rules! define_mul_assign_variants {
         }
     };
 }
+
+/// Define `FromStr`, `LowerHex` and `Display` for a compressed point wrapping a `[u8; 32]`,
+/// writing and reading the bytes of the encoding in order as hex.
+macro_rules! define_hex_encoding {
+    ($ty:ident) => {
+        impl core::str::FromStr for $ty {
+            type Err = $crate::encoding::ParseError;
+
+            /// Parse exactly 64 hex digits, optionally prefixed with `0x`, as the 32 bytes of the
+            /// encoding in order. This does not check that the encoding is valid.
+            fn from_str(s: &str) -> Result<$ty, $crate::encoding::ParseError> {
+                $crate::encoding::parse_hex_bytes(s).map($ty)
+            }
+        }
+
+        impl core::fmt::LowerHex for $ty {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::encoding::fmt_hex_bytes(&self.0, f)
+            }
+        }
+
+        impl core::fmt::Display for $ty {
+            /// Write the encoding as 64 lowercase hex digits, the same as [`LowerHex`](core::fmt::LowerHex).
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::encoding::fmt_hex_bytes(&self.0, f)
+            }
+        }
+    };
+}
@@ -31,6 +31,9 @@
 //! Scalar multiplication on `MontgomeryPoint`s is provided by the `*`
 //! operator, which implements the Montgomery ladder.
 //!
+//! Custom ladders and other \\(u\\)-only algorithms can be built from the
+//! doubling and differential addition of `MontgomeryProjective`.
+//!
 //! # Edwards Conversion
 //!
 //! The \\(2\\)-to-\\(1\\) map from the Edwards model to the Montgomery
@@ -54,9 +57,14 @@ use core::{
     ops::{Mul, MulAssign},
 };
 
-use crate::constants::{APLUS2_OVER_FOUR, MONTGOMERY_A, MONTGOMERY_A_NEG};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::constants::{
+    APLUS2_OVER_FOUR, ED25519_SQRTAM2, EIGHT_TORSION, MONTGOMERY_A, MONTGOMERY_A_NEG, SQRT_M1,
+};
 use crate::edwards::{CompressedEdwardsY, EdwardsPoint};
-use crate::field::FieldElement;
+use crate::field::BackendFieldElement as FieldElement;
 use crate::scalar::{clamp_integer, Scalar};
 
 use crate::traits::Identity;
@@ -65,6 +73,9 @@ use subtle::Choice;
 use subtle::ConstantTimeEq;
 use subtle::{ConditionallyNegatable, ConditionallySelectable};
 
+#[cfg(feature = "digest")]
+use digest::{crypto_common::BlockSizeUser, generic_array::typenum::U64, Digest};
+
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
@@ -110,6 +121,8 @@ impl Identity for MontgomeryPoint {
     }
 }
 
+define_hex_encoding!(MontgomeryPoint);
+
 #[cfg(feature = "zeroize")]
 impl Zeroize for MontgomeryPoint {
     fn zeroize(&mut self) {
@@ -151,6 +164,29 @@ impl MontgomeryPoint {
         Self::mul_base(&s)
     }
 
+    /// Multiply the basepoint by `clamp_integer(bytes)`, and add the low-order point selected by
+    /// the three low bits of `bytes`, which clamping discards.
+    ///
+    /// The result is a public key that agrees with [`MontgomeryPoint::mul_base_clamped`] for
+    /// X25519, since the clamped scalar of the peer kills the low-order component, but which is
+    /// spread over the whole curve rather than the prime-order subgroup. This is needed for
+    /// public keys that are to be hidden with [`MontgomeryPoint::to_elligator_representative`],
+    /// as the representatives of points in the prime-order subgroup can be told apart from
+    /// random bytes.
+    pub fn mul_base_clamped_dirty(bytes: [u8; 32]) -> Self {
+        let s = Scalar {
+            bytes: clamp_integer(bytes),
+        };
+
+        // Select the low-order point in constant time, since it depends on the secret bytes.
+        let mut low_order = EdwardsPoint::identity();
+        for (i, point) in EIGHT_TORSION.iter().enumerate() {
+            low_order.conditional_assign(point, (bytes[0] & 7).ct_eq(&(i as u8)));
+        }
+
+        (EdwardsPoint::mul_base(&s) + low_order).to_montgomery()
+    }
+
     /// Given `self` \\( = u\_0(P) \\), and a big-endian bit representation of an integer
     /// \\(n\\), return \\( u\_0(\[n\]P) \\). This is constant time in the length of `bits`.
     ///
@@ -159,35 +195,27 @@ impl MontgomeryPoint {
     /// [here](https://neilmadden.blog/2020/05/28/whats-the-curve25519-clamping-all-about/).
     /// When in doubt, use [`Self::mul_clamped`].
     pub fn mul_bits_be(&self, bits: impl Iterator<Item = bool>) -> MontgomeryPoint {
-        // Algorithm 8 of Costello-Smith 2017
         let affine_u = FieldElement::from_bytes(&self.0);
-        let mut x0 = ProjectivePoint::identity();
-        let mut x1 = ProjectivePoint {
-            U: affine_u,
-            W: FieldElement::ONE,
-        };
-
-        // Go through the bits from most to least significant, using a sliding window of 2
-        let mut prev_bit = false;
-        for cur_bit in bits {
-            let choice: u8 = (prev_bit ^ cur_bit) as u8;
-
-            debug_assert!(choice == 0 || choice == 1);
-
-            ProjectivePoint::conditional_swap(&mut x0, &mut x1, choice.into());
-            differential_add_and_double(&mut x0, &mut x1, &affine_u);
-
-            prev_bit = cur_bit;
-        }
-        // The final value of prev_bit above is scalar.bits()[0], i.e., the LSB of scalar
-        ProjectivePoint::conditional_swap(&mut x0, &mut x1, Choice::from(prev_bit as u8));
-        // Don't leave the bit in the stack
-        #[cfg(feature = "zeroize")]
-        prev_bit.zeroize();
-
+        let (x0, _) = ladder(&affine_u, bits);
         x0.as_affine()
     }
 
+    /// Given `self` \\( = u\_0(P) \\), and a big-endian bit representation of an integer
+    /// \\(n\\), run the Montgomery ladder and return both of its outputs
+    /// \\( (u\_0(\[n\]P), u\_0(\[n+1\]P)) \\) in projective coordinates. This is constant
+    /// time in the length of `bits`.
+    ///
+    /// The second output is what the Okeya-Sakurai recovery needs, along with the full input
+    /// point, to compute \\( \[n\]P \\) with its sign, as done by
+    /// [`EdwardsPoint::mul_bits_be`].
+    #[cfg(feature = "hazmat")]
+    pub fn ladder(
+        &self,
+        bits: impl Iterator<Item = bool>,
+    ) -> (MontgomeryProjective, MontgomeryProjective) {
+        ladder(&FieldElement::from_bytes(&self.0), bits)
+    }
+
     /// View this `MontgomeryPoint` as an array of bytes.
     pub const fn as_bytes(&self) -> &[u8; 32] {
         &self.0
@@ -244,16 +272,164 @@ impl MontgomeryPoint {
 
         CompressedEdwardsY(y_bytes).decompress()
     }
+
+    /// Compute a representative of this point under the Elligator 2 map, i.e. 32 bytes that
+    /// [`MontgomeryPoint::from_elligator_representative`] maps back to `self`.
+    ///
+    /// If `self` is a uniformly random point of the curve, the representative is
+    /// indistinguishable from 32 uniformly random bytes. X25519 public keys lie in the
+    /// prime-order subgroup and must be generated with
+    /// [`MontgomeryPoint::mul_base_clamped_dirty`] for this to hold.
+    ///
+    /// # Inputs
+    ///
+    /// * `tweak`: a uniformly random byte. Its low bit chooses between the two representatives
+    ///   of the point, and its two high bits are used as the two high bits of the
+    ///   representative, which the map ignores. The other bits are unused. The point with
+    ///   \(u = 0\) only has the representative \(0\), which is returned for either low bit.
+    ///
+    /// # Return
+    ///
+    /// * `Some([u8; 32])` if `self` has a representative, which is the case for about half of
+    ///   the points of the curve;
+    ///
+    /// * `None` otherwise, or if `self` is the \\(u\\)-coordinate of a point on the twist.
+    ///
+    /// Whether a point has a representative is not hidden, so keys should be regenerated until
+    /// one is found, rather than being selected in any other way.
+    pub fn to_elligator_representative(&self, tweak: u8) -> Option<[u8; 32]> {
+        // The map sends r to w = -A / (1 + 2r^2) when w is on the curve, and to -w - A
+        // otherwise. Solving for r gives the two representatives
+        //
+        //     r^2 = -u / (2(u + A))  or  r^2 = -(u + A) / 2u,
+        //
+        // which exist whenever -2u(u + A) is square, except for u = -A. For u = 0 only the
+        // first one exists, so it is used whatever the tweak.
+        let u = FieldElement::from_bytes(&self.0);
+        let u_plus_a = &u + &MONTGOMERY_A;
+
+        let choice = Choice::from(tweak & 1) & !u.is_zero();
+        let num = FieldElement::conditional_select(&u, &u_plus_a, choice);
+        let den = FieldElement::conditional_select(&u_plus_a, &u, choice);
+        let (is_representable, mut r) = FieldElement::sqrt_ratio_i(&-&num, &(&den + &den));
+
+        // The u-coordinates of the curve are the u such that u^3 + Au^2 + u is square.
+        let (is_on_curve, _) = FieldElement::sqrt_ratio_i(
+            &(&u * &(&(&u * &u_plus_a) + &FieldElement::ONE)),
+            &FieldElement::ONE,
+        );
+
+        // Of r and -r, choose the one in [0, (p - 1) / 2], which is less than 2^254 and leaves
+        // the two high bits free. That is the one whose double is nonnegative.
+        r.conditional_negate((&r + &r).is_negative());
+
+        let mut bytes = r.as_bytes();
+        bytes[31] |= tweak & 0xc0;
+
+        let is_valid = is_representable & is_on_curve & !u_plus_a.is_zero();
+        if is_valid.into() {
+            Some(bytes)
+        } else {
+            None
+        }
+    }
+
+    /// Map a representative to a point with the Elligator 2 map, ignoring its two high bits.
+    ///
+    /// This is the inverse of [`MontgomeryPoint::to_elligator_representative`]. Every 32-byte
+    /// string is mapped to a point of the curve.
+    pub fn from_elligator_representative(representative: &[u8; 32]) -> MontgomeryPoint {
+        let mut bytes = *representative;
+        bytes[31] &= 0x3f;
+        elligator_encode(&FieldElement::from_bytes(&bytes))
+    }
+
+    #[cfg(feature = "digest")]
+    /// Hash a message to the curve with the `hash_to_curve` function of
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-3), using `D` for
+    /// `expand_message_xmd` and the domain separation tag `dst`.
+    ///
+    /// With `D = Sha512`, this is the `curve25519_XMD:SHA-512_ELL2_RO_` suite.
+    ///
+    /// # Return
+    ///
+    /// The \(u\)-coordinate of the output point \((u, v)\), and the sign of the
+    /// \(x\)-coordinate of the corresponding `EdwardsPoint`, which determines \(v\). Passing
+    /// both to [`MontgomeryPoint::to_edwards`] gives the output of
+    /// [`EdwardsPoint::hash_to_curve`] for the same message and tag, unless the output is the
+    /// identity, which happens with negligible probability.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::{edwards::EdwardsPoint, montgomery::MontgomeryPoint};
+    /// use sha2::Sha512;
+    ///
+    /// let dst = b"MyApp-V01-CS01-with-curve25519_XMD:SHA-512_ELL2_RO_";
+    /// let (u, sign) = MontgomeryPoint::hash_to_curve::<Sha512>(b"msg", dst);
+    /// assert_eq!(
+    ///     u.to_edwards(sign),
+    ///     Some(EdwardsPoint::hash_to_curve::<Sha512>(b"msg", dst))
+    /// );
+    /// ```
+    pub fn hash_to_curve<D>(msg: &[u8], dst: &[u8]) -> (MontgomeryPoint, u8)
+    where
+        D: Digest<OutputSize = U64> + BlockSizeUser + Default,
+    {
+        // The rational map to edwards25519 is an isomorphism, so hashing to either curve with the
+        // same tag gives corresponding points.
+        let point = EdwardsPoint::hash_to_curve::<D>(msg, dst);
+        to_montgomery_with_sign(&point)
+    }
+
+    #[cfg(feature = "digest")]
+    /// Hash a message to the curve with the `encode_to_curve` function of
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-3), using `D` for
+    /// `expand_message_xmd` and the domain separation tag `dst`.
+    ///
+    /// With `D = Sha512`, this is the `curve25519_XMD:SHA-512_ELL2_NU_` suite. The output is
+    /// returned as by [`MontgomeryPoint::hash_to_curve`], but is not uniformly distributed.
+    pub fn encode_to_curve<D>(msg: &[u8], dst: &[u8]) -> (MontgomeryPoint, u8)
+    where
+        D: Digest<OutputSize = U64> + BlockSizeUser + Default,
+    {
+        let point = EdwardsPoint::encode_to_curve::<D>(msg, dst);
+        to_montgomery_with_sign(&point)
+    }
+}
+
+/// Convert an `EdwardsPoint` to its Montgomery \\(u\\)-coordinate, as by
+/// [`EdwardsPoint::to_montgomery`], along with the sign bit of its compressed form.
+///
+/// We have u = (Z+Y)/(Z-Y) and x = X/Z, so both are computed from the single inversion of
+/// (Z-Y)Z rather than one inversion each.
+#[cfg(feature = "digest")]
+fn to_montgomery_with_sign(point: &EdwardsPoint) -> (MontgomeryPoint, u8) {
+    let U = &point.Z + &point.Y;
+    let W = &point.Z - &point.Y;
+    // As in `to_montgomery`, W is zero only for the identity, which gives u = 0. Its x = 0 is
+    // nonnegative as well.
+    let recip = (&W * &point.Z).invert();
+    let u = &(&U * &point.Z) * &recip;
+    let x = &(&point.X * &W) * &recip;
+    (MontgomeryPoint(u.as_bytes()), x.is_negative().unwrap_u8())
 }
 
 /// Perform the Elligator2 mapping to a Montgomery point.
 ///
 /// See <https://tools.ietf.org/html/draft-irtf-cfrg-hash-to-curve-10#section-6.7.1>
-//
-// TODO Determine how much of the hash-to-group API should be exposed after the CFRG
-//      draft gets into a more polished/accepted state.
-#[allow(unused)]
 pub(crate) fn elligator_encode(r_0: &FieldElement) -> MontgomeryPoint {
+    let (u, _v) = elligator_encode_uv(r_0);
+    MontgomeryPoint(u.as_bytes())
+}
+
+/// Perform the Elligator2 mapping to a point \((u, v)\) on the Montgomery curve.
+///
+/// The \(u\)-coordinate is the one returned by [`elligator_encode`], and the sign of \(v\)
+/// is chosen as in `map_to_curve_elligator2` from
+/// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-6.7.1): negative if the
+/// first candidate \(u\) is used, nonnegative otherwise.
+pub(crate) fn elligator_encode_uv(r_0: &FieldElement) -> (FieldElement, FieldElement) {
     let one = FieldElement::ONE;
     let d_1 = &one + &r_0.square2(); /* 2r^2 */
 
@@ -265,54 +441,84 @@ pub(crate) fn elligator_encode(r_0: &FieldElement) -> MontgomeryPoint {
     let inner = &(d_sq + &au) + &one;
     let eps = &d * &inner; /* eps = d^3 + Ad^2 + d */
 
-    let (eps_is_sq, _eps) = FieldElement::sqrt_ratio_i(&eps, &one);
+    let (eps_is_sq, eps_sqrt) = FieldElement::sqrt_ratio_i(&eps, &one);
 
     let zero = FieldElement::ZERO;
     let Atemp = FieldElement::conditional_select(&MONTGOMERY_A, &zero, eps_is_sq); /* 0, or A if nonsquare*/
     let mut u = &d + &Atemp; /* d, or d+A if nonsquare */
     u.conditional_negate(!eps_is_sq); /* d, or -d-A if nonsquare */
 
-    MontgomeryPoint(u.as_bytes())
+    // If eps is nonsquare then eps_sqrt^2 = i*eps, and the curve equation at -d-A evaluates to
+    // 2r^2 * eps. Since (1-i)^2 = -2i, its square root is r * eps_sqrt * (1-i).
+    let one_minus_i = &one - &SQRT_M1;
+    let v_nonsquare = &(r_0 * &eps_sqrt) * &one_minus_i;
+    let mut v = FieldElement::conditional_select(&v_nonsquare, &eps_sqrt, eps_is_sq);
+    v.conditional_negate(v.is_negative() ^ eps_is_sq);
+
+    (u, v)
 }
 
-/// A `ProjectivePoint` holds a point on the projective line
+/// A `MontgomeryProjective` holds a point on the projective line
 /// \\( \mathbb P(\mathbb F\_p) \\), which we identify with the Kummer
-/// line of the Montgomery curve.
+/// line of the Montgomery curve, as projective coordinates \\( (U : W) \\)
+/// with \\( u = U / W \\).
+///
+/// Like `MontgomeryPoint`, it discards the sign of the point, so it only
+/// supports doubling and differential addition, which are enough to build
+/// Montgomery ladders and other \\(x\\)-only algorithms. All its operations
+/// are constant time.
 #[derive(Copy, Clone, Debug)]
-struct ProjectivePoint {
-    pub U: FieldElement,
-    pub W: FieldElement,
+pub struct MontgomeryProjective {
+    pub(crate) U: FieldElement,
+    pub(crate) W: FieldElement,
 }
 
-impl Identity for ProjectivePoint {
-    fn identity() -> ProjectivePoint {
-        ProjectivePoint {
+impl Identity for MontgomeryProjective {
+    fn identity() -> MontgomeryProjective {
+        MontgomeryProjective {
             U: FieldElement::ONE,
             W: FieldElement::ZERO,
         }
     }
 }
 
-impl Default for ProjectivePoint {
-    fn default() -> ProjectivePoint {
-        ProjectivePoint::identity()
+impl Default for MontgomeryProjective {
+    fn default() -> MontgomeryProjective {
+        MontgomeryProjective::identity()
     }
 }
 
-impl ConditionallySelectable for ProjectivePoint {
+impl ConditionallySelectable for MontgomeryProjective {
     fn conditional_select(
-        a: &ProjectivePoint,
-        b: &ProjectivePoint,
+        a: &MontgomeryProjective,
+        b: &MontgomeryProjective,
         choice: Choice,
-    ) -> ProjectivePoint {
-        ProjectivePoint {
+    ) -> MontgomeryProjective {
+        MontgomeryProjective {
             U: FieldElement::conditional_select(&a.U, &b.U, choice),
             W: FieldElement::conditional_select(&a.W, &b.W, choice),
         }
     }
 }
 
-impl ProjectivePoint {
+#[cfg(feature = "zeroize")]
+impl Zeroize for MontgomeryProjective {
+    fn zeroize(&mut self) {
+        self.U.zeroize();
+        self.W.zeroize();
+    }
+}
+
+impl MontgomeryProjective {
+    /// Lift an affine \\(u\\)-coordinate to projective coordinates
+    /// \\( (u : 1) \\).
+    pub fn from_affine(point: &MontgomeryPoint) -> MontgomeryProjective {
+        MontgomeryProjective {
+            U: FieldElement::from_bytes(&point.0),
+            W: FieldElement::ONE,
+        }
+    }
+
     /// Dehomogenize this point to affine coordinates.
     ///
     /// # Return
@@ -323,6 +529,166 @@ impl ProjectivePoint {
         let u = &self.U * &self.W.invert();
         MontgomeryPoint(u.as_bytes())
     }
+
+    /// Dehomogenize a slice of points to affine coordinates, sharing a
+    /// single field inversion between all of them.
+    ///
+    /// The result is the same as calling `as_affine` on each point.
+    #[cfg(feature = "alloc")]
+    pub fn batch_normalize(points: &[MontgomeryProjective]) -> Vec<MontgomeryPoint> {
+        let mut inverses: Vec<FieldElement> = points.iter().map(|P| P.W).collect();
+        // Zeros are left unchanged, which maps points at infinity to 0 as in `as_affine`.
+        FieldElement::batch_invert(&mut inverses);
+        points
+            .iter()
+            .zip(inverses.iter())
+            .map(|(P, W_inv)| MontgomeryPoint((&P.U * W_inv).as_bytes()))
+            .collect()
+    }
+
+    /// Compute \\( u(\[2\]P) \\) from \\( u(P) \\), where \\(P\\) is `self`.
+    ///
+    /// This is the `xDBL` function of Costello-Smith.
+    #[rustfmt::skip] // keep alignment of explanatory comments
+    pub fn double(&self) -> MontgomeryProjective {
+        let t0 = (&self.U + &self.W).square(); // (U + W)^2
+        let t1 = (&self.U - &self.W).square(); // (U - W)^2
+        let t2 = &t0 - &t1;                    // 4 U W
+        let t3 = &t1 + &(&APLUS2_OVER_FOUR * &t2); // (U - W)^2 + ((A + 2)/4) 4 U W
+
+        MontgomeryProjective {
+            U: &t0 * &t1,                      // (U^2 - W^2)^2
+            W: &t2 * &t3,
+        }
+    }
+
+    /// Compute \\( u(P + Q) \\) from \\( u(P) \\), \\( u(Q) \\) and their
+    /// difference \\( u(P - Q) \\).
+    ///
+    /// This is the `xADD` function of Costello-Smith. The result is
+    /// meaningless if the difference is \\( (0 : 1) \\) or at infinity, i.e.
+    /// if \\( P - Q \\) has order dividing \\(2\\).
+    #[rustfmt::skip] // keep alignment of explanatory comments
+    pub fn differential_add(
+        P: &MontgomeryProjective,
+        Q: &MontgomeryProjective,
+        PmQ: &MontgomeryProjective,
+    ) -> MontgomeryProjective {
+        let t0 = &(&P.U + &P.W) * &(&Q.U - &Q.W); // U_P U_Q + W_P U_Q - U_P W_Q - W_P W_Q
+        let t1 = &(&P.U - &P.W) * &(&Q.U + &Q.W); // U_P U_Q - W_P U_Q + U_P W_Q - W_P W_Q
+
+        MontgomeryProjective {
+            U: &PmQ.W * &(&t0 + &t1).square(),     // W_D * 4 (U_P U_Q - W_P W_Q)^2
+            W: &PmQ.U * &(&t0 - &t1).square(),     // U_D * 4 (W_P U_Q - U_P W_Q)^2
+        }
+    }
+}
+
+/// Run the Montgomery ladder on \\( u = u(P) \\) with the big-endian bits of an integer
+/// \\(n\\), returning \\( (u(\[n\]P), u(\[n+1\]P)) \\) in projective coordinates.
+///
+/// This is constant time in the length of `bits`.
+fn ladder(
+    affine_u: &FieldElement,
+    bits: impl Iterator<Item = bool>,
+) -> (MontgomeryProjective, MontgomeryProjective) {
+    // Algorithm 8 of Costello-Smith 2017
+    let mut x0 = MontgomeryProjective::identity();
+    let mut x1 = MontgomeryProjective {
+        U: *affine_u,
+        W: FieldElement::ONE,
+    };
+
+    // Go through the bits from most to least significant, using a sliding window of 2
+    let mut prev_bit = false;
+    for cur_bit in bits {
+        let choice: u8 = (prev_bit ^ cur_bit) as u8;
+
+        debug_assert!(choice == 0 || choice == 1);
+
+        MontgomeryProjective::conditional_swap(&mut x0, &mut x1, choice.into());
+        differential_add_and_double(&mut x0, &mut x1, affine_u);
+
+        prev_bit = cur_bit;
+    }
+    // The final value of prev_bit above is scalar.bits()[0], i.e., the LSB of scalar
+    MontgomeryProjective::conditional_swap(&mut x0, &mut x1, Choice::from(prev_bit as u8));
+    // Don't leave the bit in the stack
+    #[cfg(feature = "zeroize")]
+    prev_bit.zeroize();
+
+    (x0, x1)
+}
+
+/// Compute \\( \[n\]P \\) for an `EdwardsPoint` \\(P\\) and the big-endian bits of an
+/// integer \\(n\\), using the Montgomery ladder followed by the Okeya-Sakurai recovery of the
+/// \\(v\\)-coordinate of the result.
+///
+/// This is constant time in the length of `bits`.
+#[rustfmt::skip] // keep alignment of explanatory comments
+pub(crate) fn mul_bits_be_edwards(
+    point: &EdwardsPoint,
+    bits: impl Iterator<Item = bool>,
+) -> EdwardsPoint {
+    // Map P to the Montgomery curve with
+    //   u = (1+y)/(1-y) = (Z+Y)/(Z-Y),
+    //   v = sqrt(-(A+2)) u/x = sqrt(-(A+2)) (Z+Y)Z / ((Z-Y)X),
+    // sharing a single inversion. The denominator is zero when x = 0, i.e. for the identity
+    // and the 2-torsion point (0,-1), whose multiples are computed separately below.
+    let ZpY = &point.Z + &point.Y;
+    let inv = (&(&point.Z - &point.Y) * &point.X).invert();
+    let u = &(&ZpY * &point.X) * &inv;
+    let v = &(&(&ED25519_SQRTAM2 * &ZpY) * &point.Z) * &inv;
+
+    // Keep the least significant bit of n, which gives the multiples of (0,-1).
+    let mut lsb = false;
+    let (Q, QpP) = ladder(&u, bits.inspect(|bit| lsb = *bit));
+    let n_is_odd = Choice::from(lsb as u8);
+    // Don't leave the bit in the stack
+    #[cfg(feature = "zeroize")]
+    lsb.zeroize();
+
+    // Algorithm 5 of Costello-Smith 2017: recover Q = [n]P as (X : Y : Z) from
+    // u(Q) = (U_Q : W_Q), u(Q+P) = (U_QpP : W_QpP) and the affine point P = (u, v).
+    let t1 = &u * &Q.W;                     // u W_Q
+    let t2 = &Q.U + &t1;                    // U_Q + u W_Q
+    let t3 = (&Q.U - &t1).square();         // (U_Q - u W_Q)^2
+    let t4 = &t3 * &QpP.U;                  // (U_Q - u W_Q)^2 U_QpP
+    let t5 = &(&MONTGOMERY_A + &MONTGOMERY_A) * &Q.W; // 2A W_Q
+    let t6 = &t2 + &t5;                     // U_Q + u W_Q + 2A W_Q
+    let t7 = &(&u * &Q.U) + &Q.W;           // u U_Q + W_Q
+    let t8 = &(&(&t6 * &t7) - &(&t5 * &Q.W)) * &QpP.W;
+    let Y = &t8 - &t4;
+    let t9 = &(&(&v + &v) * &Q.W) * &QpP.W; // 2 v W_Q W_QpP
+    let X = &t9 * &Q.U;
+    let Z = &t9 * &Q.W;
+
+    // Map (X : Y : Z) back to the Edwards curve with
+    //   x = sqrt(-(A+2)) u/v = sqrt(-(A+2)) X/Y,
+    //   y = (u-1)/(u+1) = (X-Z)/(X+Z).
+    // The denominators vanish only at the Montgomery identity and 2-torsion point, which are
+    // handled below, since u = -1 is not on the curve.
+    let sX = &ED25519_SQRTAM2 * &X;
+    let XpZ = &X + &Z;
+    let XmZ = &X - &Z;
+    let mut result = EdwardsPoint {
+        X: &sX * &XpZ,
+        Y: &Y * &XmZ,
+        Z: &Y * &XpZ,
+        T: &sX * &XmZ,
+    };
+
+    // The formulas above are incomplete. [n]P is the 2-torsion point (0,-1) if it has v = 0,
+    // it is -P if [n+1]P is the identity, and it is the identity if u([n]P) is at infinity.
+    result.conditional_assign(&EIGHT_TORSION[4], Y.is_zero());
+    result.conditional_assign(&-point, QpP.W.is_zero());
+    result.conditional_assign(&EdwardsPoint::identity(), Q.W.is_zero());
+
+    // The ladder degenerates when P has u = 0. Then P is the identity or (0,-1), and [n]P is
+    // P if n is odd and the identity otherwise.
+    let small_multiple = EdwardsPoint::conditional_select(&EdwardsPoint::identity(), point, n_is_odd);
+    result.conditional_assign(&small_multiple, point.X.is_zero());
+    result
 }
 
 /// Perform the double-and-add step of the Montgomery ladder.
@@ -341,8 +707,8 @@ impl ProjectivePoint {
 /// $$
 #[rustfmt::skip] // keep alignment of explanatory comments
 fn differential_add_and_double(
-    P: &mut ProjectivePoint,
-    Q: &mut ProjectivePoint,
+    P: &mut MontgomeryProjective,
+    Q: &mut MontgomeryProjective,
     affine_PmQ: &FieldElement,
 ) {
     let t0 = &P.U + &P.W;
@@ -435,12 +801,72 @@ mod test {
 
     #[test]
     fn identity_in_different_coordinates() {
-        let id_projective = ProjectivePoint::identity();
+        let id_projective = MontgomeryProjective::identity();
         let id_montgomery = id_projective.as_affine();
 
         assert!(id_montgomery == MontgomeryPoint::identity());
     }
 
+    #[test]
+    fn projective_double_and_differential_add() {
+        let mut csprng = rand_core::OsRng;
+
+        let B = constants::X25519_BASEPOINT;
+        let a = Scalar::random(&mut csprng);
+        let b = Scalar::random(&mut csprng);
+        let P = MontgomeryProjective::from_affine(&(a * B));
+        let Q = MontgomeryProjective::from_affine(&(b * B));
+        let PmQ = MontgomeryProjective::from_affine(&((a - b) * B));
+
+        // Scale the coordinates to check that the formulas are projective.
+        let scale = |X: &MontgomeryProjective, s: u8| {
+            let s = FieldElement::from_bytes(&[s; 32]);
+            MontgomeryProjective {
+                U: &X.U * &s,
+                W: &X.W * &s,
+            }
+        };
+
+        assert_eq!(P.double().as_affine(), (a + a) * B);
+        assert_eq!(scale(&P, 3).double().as_affine(), (a + a) * B);
+        assert_eq!(
+            MontgomeryProjective::differential_add(&P, &Q, &PmQ).as_affine(),
+            (a + b) * B
+        );
+        assert_eq!(
+            MontgomeryProjective::differential_add(&scale(&P, 5), &scale(&Q, 7), &scale(&PmQ, 9))
+                .as_affine(),
+            (a + b) * B
+        );
+
+        let id = MontgomeryProjective::identity();
+        assert_eq!(id.double().as_affine(), MontgomeryPoint::identity());
+        assert_eq!(
+            MontgomeryProjective::differential_add(&P, &id, &P).as_affine(),
+            P.as_affine()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn projective_batch_normalize() {
+        let mut csprng = rand_core::OsRng;
+
+        let mut points: Vec<MontgomeryProjective> = (0..8)
+            .map(|_| {
+                let P = MontgomeryProjective::from_affine(&MontgomeryPoint::mul_base(
+                    &Scalar::random(&mut csprng),
+                ));
+                P.double()
+            })
+            .collect();
+        points.push(MontgomeryProjective::identity());
+
+        let expected: Vec<MontgomeryPoint> = points.iter().map(|P| P.as_affine()).collect();
+        assert_eq!(MontgomeryProjective::batch_normalize(&points), expected);
+        assert!(MontgomeryProjective::batch_normalize(&[]).is_empty());
+    }
+
     #[test]
     fn identity_in_different_models() {
         assert!(EdwardsPoint::identity().to_montgomery() == MontgomeryPoint::identity());
@@ -486,6 +912,22 @@ mod test {
         );
     }
 
+    /// Test that the single-inversion conversion used by hashing matches `to_montgomery` and
+    /// `compress`, including for the identity.
+    #[test]
+    #[cfg(feature = "digest")]
+    fn edwards_to_montgomery_with_sign() {
+        let mut csprng = rand_core::OsRng;
+        let mut p = EdwardsPoint::identity();
+        for _ in 0..16 {
+            let sign = p.compress().as_bytes()[31] >> 7;
+            assert_eq!(to_montgomery_with_sign(&p), (p.to_montgomery(), sign));
+            let sign = (-p).compress().as_bytes()[31] >> 7;
+            assert_eq!(to_montgomery_with_sign(&-p), ((-p).to_montgomery(), sign));
+            p += EdwardsPoint::mul_base(&Scalar::random(&mut csprng));
+        }
+    }
+
     /// Check that Montgomery -> Edwards fails for points on the twist.
     #[test]
     fn montgomery_to_edwards_rejects_twist() {
@@ -572,6 +1014,36 @@ mod test {
         }
     }
 
+    // Tests that both outputs of the ladder are the multiples [n]P and [n+1]P
+    #[test]
+    #[cfg(feature = "hazmat")]
+    fn montgomery_ladder_outputs() {
+        let mut csprng = rand_core::OsRng;
+
+        for _ in 0..100 {
+            let p_edwards = rand_prime_order_point(&mut csprng);
+            let p_montgomery: MontgomeryPoint = p_edwards.to_montgomery();
+
+            let mut bigint = [0u8; 64];
+            csprng.fill_bytes(&mut bigint[..]);
+            let bigint_bits_be = bytestring_bits_le(&bigint).rev();
+
+            let n = Scalar::from_bytes_mod_order_wide(&bigint);
+            let (x0, x1) = p_montgomery.ladder(bigint_bits_be.clone());
+            assert_eq!(x0.as_affine(), p_montgomery.mul_bits_be(bigint_bits_be));
+            assert_eq!(x0.as_affine(), (n * p_edwards).to_montgomery());
+            assert_eq!(
+                x1.as_affine(),
+                ((n + Scalar::ONE) * p_edwards).to_montgomery()
+            );
+        }
+
+        // With no bits, the outputs are the identity and P
+        let (x0, x1) = constants::X25519_BASEPOINT.ladder(core::iter::empty());
+        assert!(bool::from(x0.W.is_zero()));
+        assert_eq!(x1.as_affine(), constants::X25519_BASEPOINT);
+    }
+
     // Tests that MontgomeryPoint::mul_bits_be is consistent on any point, even ones that might be
     // on the curve's twist. Specifically, this tests that b₁(b₂P) == b₂(b₁P) for random
     // integers b₁, b₂ and random (curve or twist) point P.
@@ -650,6 +1122,267 @@ mod test {
         assert_eq!(eg.to_bytes(), ELLIGATOR_CORRECT_OUTPUT);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn montgomery_elligator_representative_correct() {
+        let bytes: Vec<u8> = (0u8..32u8).collect();
+        let mut representative: [u8; 32] = (&bytes[..]).try_into().expect("Range invariant broken");
+
+        let point = MontgomeryPoint(ELLIGATOR_CORRECT_OUTPUT);
+        assert_eq!(point.to_elligator_representative(0), Some(representative));
+        assert_eq!(
+            MontgomeryPoint::from_elligator_representative(&representative),
+            point
+        );
+
+        // The high bits come from the tweak, and are ignored by the map.
+        representative[31] |= 0xc0;
+        assert_eq!(
+            point.to_elligator_representative(0xc0),
+            Some(representative)
+        );
+        assert_eq!(
+            MontgomeryPoint::from_elligator_representative(&representative),
+            point
+        );
+    }
+
+    #[test]
+    fn montgomery_elligator_representative_round_trip() {
+        let mut csprng = rand_core::OsRng;
+
+        let mut representable = 0;
+        for _ in 0..100 {
+            let mut bytes = [0u8; 32];
+            csprng.fill_bytes(&mut bytes);
+            let point = MontgomeryPoint::mul_base_clamped_dirty(bytes);
+
+            let tweak = csprng.next_u32() as u8;
+            let representatives = [
+                point.to_elligator_representative(tweak & !1),
+                point.to_elligator_representative(tweak | 1),
+            ];
+            match representatives {
+                [Some(r_0), Some(r_1)] => {
+                    representable += 1;
+                    assert_ne!(r_0, r_1);
+                    for r in [r_0, r_1] {
+                        assert_eq!(r[31] & 0xc0, tweak & 0xc0);
+                        assert_eq!(MontgomeryPoint::from_elligator_representative(&r), point);
+                    }
+                }
+                [None, None] => {}
+                _ => panic!("a point should have both representatives or neither"),
+            }
+        }
+
+        // About half of the points are representable.
+        assert!((25..=75).contains(&representable));
+    }
+
+    /// The representatives of some u-coordinates of the curve for each low bit of the tweak, or
+    /// `None` where a u-coordinate has none, as little-endian hex.
+    ///
+    /// These are not test vectors from another implementation. They were computed with a
+    /// standalone Python model of the map and its inverse written from section 5 of the Elligator
+    /// paper (Bernstein, Hamburg, Krasnova and Lange, 2013), with the root taken in
+    /// \\([0, (p-1)/2]\\) and each representative checked by mapping it forward. The model uses
+    /// Python integers, so the vectors are independent of the field arithmetic of this crate.
+    const ELLIGATOR_REPRESENTATIVES: [(&str, Option<[&str; 2]>); 10] = [
+        (
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            Some([
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            ]),
+        ),
+        (
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            Some([
+                "0febd3aaa878785101036804ec3fa24df2c6db0200407f055656ac5e2ffd371f",
+                "ce396c5424c947d204df287d2c5c1bf41dbb57183ad5ba142c23d35351aea01d",
+            ]),
+        ),
+        (
+            "0900000000000000000000000000000000000000000000000000000000000000",
+            Some([
+                "a1b146107da32a888fd12b270aa14c2ec61d330f0e007f56092f9a02da0a7f34",
+                "b9762dadc1db2944f08aeb419d76f6b19e66fd47ec1076dfe7a7a1c4e0f0a92b",
+            ]),
+        ),
+        (
+            "0a00000000000000000000000000000000000000000000000000000000000000",
+            Some([
+                "33d6cb3168b1fd2c3ddcaf1d34910cc139e86590e7f0e803d0e6774d4aab2810",
+                "cafda3c66d2e06ab4a348b46c54ee70937f06160f9a858809a82d89efdbed919",
+            ]),
+        ),
+        (
+            "1300000000000000000000000000000000000000000000000000000000000000",
+            Some([
+                "eee264ea55132efe860749c98d0d1335a1e36491c2332e1b92b57a52292f332a",
+                "4ccad9af4234cb93e0a379e4f960f908343a6e63313419d429247d394ab53810",
+            ]),
+        ),
+        (
+            "2600000000000000000000000000000000000000000000000000000000000000",
+            Some([
+                "ffa1b641848f14714c640794cc4f14995743b96e15e1dd7e997dbac62fe2fc0d",
+                "0dae028a7019c58071f872961dffd7363bd1a7dc56a739e1097cdcb513263d0f",
+            ]),
+        ),
+        (
+            "0700000000000000000000000000000000000000000000000000000000000040",
+            Some([
+                "d2ed76d8f34a0a35a2584f9c2e7f44ef8bf812358eb47b1fa192252048e64715",
+                "addc4c81bc7e172d3cdc9808fb674ede6bc2e3efe685a8ea7cbca021e0db953d",
+            ]),
+        ),
+        (
+            "0800000000000000000000000000000000000000000000000000000000000000",
+            None,
+        ),
+        (
+            "0f00000000000000000000000000000000000000000000000000000000000000",
+            None,
+        ),
+        (
+            "ebffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            None,
+        ),
+    ];
+
+    fn bytes_from_hex(hex: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        hex::decode_to_slice(hex, &mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn montgomery_elligator_representative_vectors() {
+        for (u, representatives) in ELLIGATOR_REPRESENTATIVES.iter() {
+            let point = MontgomeryPoint(bytes_from_hex(u));
+            match representatives {
+                Some(representatives) => {
+                    for (tweak, r) in representatives.iter().enumerate() {
+                        let r = bytes_from_hex(r);
+                        assert_eq!(point.to_elligator_representative(tweak as u8), Some(r));
+                        assert_eq!(MontgomeryPoint::from_elligator_representative(&r), point);
+                    }
+                }
+                None => {
+                    assert_eq!(point.to_elligator_representative(0), None);
+                    assert_eq!(point.to_elligator_representative(1), None);
+                }
+            }
+        }
+    }
+
+    /// The x-coordinate of P and u[0] of the `curve25519_XMD:SHA-512_ELL2_NU_` vectors from RFC
+    /// 9380 Appendix J.4.2, as big-endian hex. P is the point which u[0] maps to, multiplied by
+    /// the cofactor.
+    const CURVE25519_NU: [[&str; 2]; 5] = [
+        [
+            "1bb913f0c9daefa0b3375378ffa534bda5526c97391952a7789eb976edfe4d08",
+            "608d892b641f0328523802a6603427c26e55e6f27e71a91a478148d45b5093cd",
+        ],
+        [
+            "7c22950b7d900fa866334262fcaea47a441a578df43b894b4625c9b450f9a026",
+            "46f5b22494bfeaa7f232cc8d054be68561af50230234d7d1d63d1d9abeca8da5",
+        ],
+        [
+            "31ad08a8b0deeb2a4d8b0206ca25f567ab4e042746f792f4b7973f3ae2096c52",
+            "235fe40c443766ce7e18111c33862d66c3b33267efa50d50f9e8e5d252a40aaa",
+        ],
+        [
+            "027877759d155b1997d0d84683a313eb78bdb493271d935b622900459d52ceaa",
+            "001e92a544463bda9bd04ddbe3d6eed248f82de32f522669efc5ddce95f46f5b",
+        ],
+        [
+            "5fd892c0958d1a75f54c3182a18d286efab784e774d1e017ba2fb252998b5dc1",
+            "1a68a1af9f663592291af987203393f707305c7bac9c8d63d6a729bdc553dc19",
+        ],
+    ];
+
+    /// Check the Elligator 2 map and its inverse against the RFC 9380 vectors.
+    #[test]
+    fn montgomery_elligator_representative_rfc9380() {
+        for [x, u0] in CURVE25519_NU.iter() {
+            let mut x = bytes_from_hex(x);
+            x.reverse();
+            let mut u0 = bytes_from_hex(u0);
+            u0.reverse();
+
+            // The map is even, so either of u[0] and -u[0] is a representative of the point.
+            let mut r = FieldElement::from_bytes(&u0);
+            r.conditional_negate((&r + &r).is_negative());
+            let representative = r.as_bytes();
+
+            let point = MontgomeryPoint::from_elligator_representative(&representative);
+            let cleared = point.mul_bits_be([true, false, false, false].iter().copied());
+            assert_eq!(cleared.0, x);
+
+            let representatives = [
+                point.to_elligator_representative(0).unwrap(),
+                point.to_elligator_representative(1).unwrap(),
+            ];
+            assert!(representatives.contains(&representative));
+            for r in representatives {
+                assert_eq!(MontgomeryPoint::from_elligator_representative(&r), point);
+            }
+        }
+    }
+
+    #[test]
+    fn montgomery_elligator_twist_not_representable() {
+        // u = 2 is the u-coordinate of a point on the twist.
+        let mut twist = [0u8; 32];
+        twist[0] = 2;
+        assert!(MontgomeryPoint(twist).to_edwards(0).is_none());
+        assert_eq!(MontgomeryPoint(twist).to_elligator_representative(0), None);
+        assert_eq!(MontgomeryPoint(twist).to_elligator_representative(1), None);
+    }
+
+    #[test]
+    fn montgomery_elligator_zero_representative() {
+        let zero = MontgomeryPoint([0u8; 32]);
+        assert_eq!(zero.to_elligator_representative(0), Some([0u8; 32]));
+        assert_eq!(zero.to_elligator_representative(1), Some([0u8; 32]));
+
+        let mut representative = [0u8; 32];
+        representative[31] = 0xc0;
+        assert_eq!(zero.to_elligator_representative(0xc1), Some(representative));
+        assert_eq!(
+            MontgomeryPoint::from_elligator_representative(&representative),
+            zero
+        );
+    }
+
+    #[test]
+    fn mul_base_clamped_dirty() {
+        let mut csprng = rand_core::OsRng;
+
+        for low_bits in 0..8 {
+            let mut a_bytes = [0u8; 32];
+            let mut b_bytes = [0u8; 32];
+            csprng.fill_bytes(&mut a_bytes);
+            csprng.fill_bytes(&mut b_bytes);
+            a_bytes[0] = (a_bytes[0] & !7) | low_bits;
+
+            // The low-order component is selected by the low bits.
+            let dirty = MontgomeryPoint::mul_base_clamped_dirty(a_bytes);
+            let expected =
+                EdwardsPoint::mul_base_clamped(a_bytes) + EIGHT_TORSION[low_bits as usize];
+            assert_eq!(dirty, expected.to_montgomery());
+
+            // It does not change the shared secret.
+            assert_eq!(
+                dirty.mul_clamped(b_bytes),
+                MontgomeryPoint::mul_base_clamped(a_bytes).mul_clamped(b_bytes)
+            );
+        }
+    }
+
     #[test]
     fn montgomery_elligator_zero_zero() {
         let zero = [0u8; 32];
@@ -0,0 +1,133 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Untrusted answers from the host for the RISC Zero backend.
+//!
+//! Some values, such as inverses, take hundreds of precompile calls to compute inside the zkvm
+//! guest but only one or two to check. The RISC Zero backend can therefore ask the host for
+//! them. Every answer is checked in the guest before it is used. An answer which fails its check
+//! is recorded in [`risc0_fault`](crate::risc0_fault), and the guest computes the value itself
+//! instead, so a misbehaving host can slow the guest down but cannot change any result.
+//!
+//! This crate does not depend on the zkvm, so the guest program passes the questions on to the
+//! host itself. It installs a [`Handler`] with [`set_handler`], which sends each [`Query`] to the
+//! host over a channel of its choice, and the host computes the reply with [`answer`]:
+//!
+//! ```ignore
+//! // In the guest.
+//! fn ask_host(query: &Query) -> [u8; 32] {
+//!     // Encode `query`, send it to the host and return the 32 bytes it replies with, e.g. with
+//!     // `risc0_zkvm::guest::env::send_recv_slice`.
+//! }
+//! risc0_hint::set_handler(Some(&(ask_host as risc0_hint::Handler)));
+//!
+//! // In the host, after decoding the query sent by the guest.
+//! let reply = risc0_hint::answer(&query);
+//! ```
+//!
+//! Without a handler, which is the default, the guest computes every value itself. The values
+//! sent to the host are not secret from it: the host of a zkvm guest is the prover, which knows
+//! every input of the computation anyway.
+
+#[cfg(any(
+    curve25519_dalek_backend = "risc0",
+    curve25519_dalek_backend = "risc0-emulated"
+))]
+use core::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+
+use crate::field::BackendFieldElement as FieldElement;
+use crate::scalar::Scalar;
+
+/// A question the RISC Zero backend asks the host.
+///
+/// Every value is given as its canonical 32-byte little-endian encoding, and so is the answer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Query {
+    /// The inverse of a field element modulo \\( p = 2\^{255} - 19 \\), or zero if it is zero.
+    FieldInvert([u8; 32]),
+    /// The nonnegative square root of `u/v` modulo \\( p \\) if it is a square, and of `i*u/v`
+    /// otherwise, or zero if `v` is zero, as returned by `sqrt_ratio_i`. The operands are `u` and
+    /// `v`.
+    FieldSqrtRatioI([u8; 32], [u8; 32]),
+    /// The inverse of a scalar modulo the group order \\( \ell \\), or zero if it is zero.
+    ScalarInvert([u8; 32]),
+    /// The square root with an even encoding of a scalar modulo \\( \ell \\) if it is a
+    /// square, and of twice the scalar otherwise, which is then a square since \\(2\\) is not.
+    ScalarSqrt([u8; 32]),
+}
+
+/// Compute the answer of a cooperative host to `query`.
+///
+/// This uses the arithmetic of the selected backend, so it can run on the host with any backend.
+/// With the RISC Zero backend, queries asked while answering are not passed on to the handler,
+/// so this can also serve as the handler when the precompile is emulated.
+pub fn answer(query: &Query) -> [u8; 32] {
+    match query {
+        Query::FieldInvert(x) => FieldElement::from_bytes(x).invert().as_bytes(),
+        Query::FieldSqrtRatioI(u, v) => {
+            let u = FieldElement::from_bytes(u);
+            let v = FieldElement::from_bytes(v);
+            FieldElement::sqrt_ratio_i(&u, &v).1.as_bytes()
+        }
+        Query::ScalarInvert(x) => Scalar::from_bytes_mod_order(*x).invert().to_bytes(),
+        Query::ScalarSqrt(x) => {
+            let a = Scalar::from_bytes_mod_order(*x);
+            let root = a.sqrt();
+            let root = if bool::from(root.is_some()) {
+                root
+            } else {
+                (a + a).sqrt()
+            };
+            root.unwrap().to_bytes()
+        }
+    }
+}
+
+/// A function which passes a [`Query`] on to the host and returns its answer.
+pub type Handler = fn(&Query) -> [u8; 32];
+
+#[cfg(any(
+    curve25519_dalek_backend = "risc0",
+    curve25519_dalek_backend = "risc0-emulated"
+))]
+static HANDLER: AtomicPtr<Handler> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Set while a query is being answered, so that a handler which calls back into this crate, such
+/// as [`answer`], is not asked again.
+#[cfg(any(
+    curve25519_dalek_backend = "risc0",
+    curve25519_dalek_backend = "risc0-emulated"
+))]
+static ANSWERING: AtomicBool = AtomicBool::new(false);
+
+/// Install the handler through which the RISC Zero backend asks the host, or remove it with
+/// `None`.
+#[cfg(any(
+    curve25519_dalek_backend = "risc0",
+    curve25519_dalek_backend = "risc0-emulated"
+))]
+pub fn set_handler(handler: Option<&'static Handler>) {
+    let handler = handler.map_or(core::ptr::null_mut(), |handler| {
+        handler as *const Handler as *mut Handler
+    });
+    HANDLER.store(handler, Ordering::Release);
+}
+
+/// Ask the host `query`, returning `None` if there is no handler to ask. The answer is untrusted.
+#[cfg(any(
+    curve25519_dalek_backend = "risc0",
+    curve25519_dalek_backend = "risc0-emulated"
+))]
+pub(crate) fn ask(query: &Query) -> Option<[u8; 32]> {
+    let handler = HANDLER.load(Ordering::Acquire);
+    if handler.is_null() || ANSWERING.swap(true, Ordering::Acquire) {
+        return None;
+    }
+    // SAFETY: `HANDLER` is either null or set by `set_handler` from a `&'static Handler`.
+    let handler = unsafe { *handler };
+    let answer = handler(query);
+    ANSWERING.store(false, Ordering::Release);
+    Some(answer)
+}
@@ -172,25 +172,29 @@ use core::ops::{Mul, MulAssign};
 #[cfg(any(test, feature = "rand_core"))]
 use rand_core::CryptoRngCore;
 
+#[cfg(feature = "digest")]
+use digest::crypto_common::BlockSizeUser;
 #[cfg(feature = "digest")]
 use digest::generic_array::typenum::U64;
 #[cfg(feature = "digest")]
 use digest::Digest;
 
 use crate::constants;
-use crate::field::FieldElement;
+use crate::field::BackendFieldElement as FieldElement;
+#[cfg(feature = "digest")]
+use crate::hash_to_curve;
 
 #[cfg(feature = "group")]
 use {
     group::{cofactor::CofactorGroup, prime::PrimeGroup, GroupEncoding},
     rand_core::RngCore,
-    subtle::CtOption,
 };
 
 use subtle::Choice;
 use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
+use subtle::CtOption;
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
@@ -353,6 +357,8 @@ impl TryFrom<&[u8]> for CompressedRistretto {
     }
 }
 
+define_hex_encoding!(CompressedRistretto);
+
 // ------------------------------------------------------------------------
 // Serde support
 // ------------------------------------------------------------------------
@@ -651,8 +657,8 @@ impl RistrettoPoint {
     ///
     /// # Note
     ///
-    /// This method is not public because it's just used for hashing
-    /// to a point -- proper elligator support is deferred for now.
+    /// This is exposed on bytes rather than field elements as
+    /// [`RistrettoPoint::from_uniform_bytes_single_elligator`].
     pub(crate) fn elligator_ristretto_flavor(r_0: &FieldElement) -> RistrettoPoint {
         let i = &constants::SQRT_M1;
         let d = &constants::EDWARDS_D;
@@ -691,6 +697,87 @@ impl RistrettoPoint {
         )
     }
 
+    /// Construct a `RistrettoPoint` from 32 bytes with a single
+    /// application of the Ristretto Elligator map, ignoring the high
+    /// bit of the last byte.
+    ///
+    /// Unlike [`RistrettoPoint::from_uniform_bytes`], the output is
+    /// not uniformly distributed even if the input is. The map is
+    /// invertible, though: [`RistrettoPoint::elligator_ristretto_flavor_inverse`]
+    /// recovers the input from the output, which allows data to be
+    /// embedded into points, as in the Lizard encoding.
+    ///
+    /// The bytes `b` and the encoding of \(-b mod p\) are mapped
+    /// to the same point.
+    pub fn from_uniform_bytes_single_elligator(bytes: &[u8; 32]) -> RistrettoPoint {
+        RistrettoPoint::elligator_ristretto_flavor(&FieldElement::from_bytes(bytes))
+    }
+
+    /// Compute the preimages of this point under
+    /// [`RistrettoPoint::from_uniform_bytes_single_elligator`].
+    ///
+    /// Each point has at most eight preimages up to sign, and each
+    /// one is returned as the canonical encoding of its nonnegative
+    /// representative, i.e. with the low bit of the first byte clear.
+    /// The entries which are not preimages are none. This runs in
+    /// constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// let mut bytes = [0x42u8; 32];
+    /// bytes[31] &= 0x7f;
+    ///
+    /// let P = RistrettoPoint::from_uniform_bytes_single_elligator(&bytes);
+    /// let preimages = P.elligator_ristretto_flavor_inverse();
+    /// assert!(preimages.iter().any(|r| bool::from(r.is_some()) && r.unwrap() == bytes));
+    /// ```
+    pub fn elligator_ristretto_flavor_inverse(&self) -> [CtOption<[u8; 32]>; 8] {
+        // The map sends r_0 to a point (s, t) of the Jacobi quartic, which is sent to the Edwards
+        // point (x, y) = (2s / t, (1 - s^2) / (1 + s^2)). Each of the four Edwards points in the
+        // coset of self has two points (s, t) and (-s, -t) above it, with s^2 = (1 - y) / (1 + y).
+        // Writing a = (d + 1) / (d - 1) * (1 + t / sqrt(a*d - 1)), the forward map gives
+        //
+        //     r_0^2 = -i (s^2 + a) / (s^2 - a)   if s is nonnegative,
+        //     r_0^2 = -i (s^2 - a) / (s^2 + a)   otherwise,
+        //
+        // which is solved below after multiplying through by X (d - 1).
+        let one = FieldElement::ONE;
+        let d_plus_one = &constants::EDWARDS_D + &one;
+        let d_minus_one = &constants::EDWARDS_D - &one;
+
+        let mut preimages = [CtOption::new([0u8; 32], Choice::from(0)); 8];
+        for (i, Q) in self.coset4().iter().enumerate() {
+            let (s_is_defined, s) = FieldElement::sqrt_ratio_i(&(&Q.Z - &Q.Y), &(&Q.Z + &Q.Y));
+            // X t / sqrt(a*d - 1) is s times 2Z / sqrt(a*d - 1), as t = 2s / x. With the roots
+            // chosen for the constants, 1 / sqrt(a*d - 1) = -1 / sqrt(a - d).
+            let two_z_over_c = -&(&(&Q.Z + &Q.Z) * &constants::INVSQRT_A_MINUS_D);
+
+            for (j, s) in [s, -&s].iter().enumerate() {
+                let n = &(&d_minus_one * &s.square()) * &Q.X;
+                let m = &d_plus_one * &(&Q.X + &(s * &two_z_over_c));
+                let mut num = &n + &m;
+                let mut den = &n - &m;
+                FieldElement::conditional_swap(&mut num, &mut den, s.is_negative());
+
+                let (r_0_is_defined, mut r_0) =
+                    FieldElement::sqrt_ratio_i(&-&(&constants::SQRT_M1 * &num), &den);
+
+                // The identity has s = 0 for both points above it, which the formulas above
+                // cannot tell apart. The second one is the image of sqrt(i*d).
+                let is_identity_dual = s.is_zero() & Choice::from(j as u8);
+                r_0.conditional_assign(&constants::SQRT_ID, is_identity_dual);
+
+                preimages[2 * i + j] = CtOption::new(
+                    r_0.as_bytes(),
+                    s_is_defined & (r_0_is_defined | is_identity_dual),
+                );
+            }
+        }
+        preimages
+    }
+
     #[cfg(any(test, feature = "rand_core"))]
     /// Return a `RistrettoPoint` chosen uniformly at random using a user-provided RNG.
     ///
@@ -724,6 +811,10 @@ impl RistrettoPoint {
     ///
     /// Convenience wrapper around `from_hash`.
     ///
+    /// The input is hashed without a domain separation tag. New
+    /// protocols should prefer `hash_to_group`, which follows RFC 9380
+    /// and RFC 9496.
+    ///
     /// # Implementation
     ///
     /// Uses the Ristretto-flavoured Elligator 2 map, so that the
@@ -773,6 +864,35 @@ impl RistrettoPoint {
         RistrettoPoint::from_uniform_bytes(&output_bytes)
     }
 
+    #[cfg(feature = "digest")]
+    /// Hash a message into a `RistrettoPoint` with the `hash_to_group`
+    /// function of [RFC 9496](https://www.rfc-editor.org/rfc/rfc9496.html#section-4.3.4),
+    /// using `expand_message_xmd` with `D` and the domain separation
+    /// tag `dst`, where the message is the concatenation of the slices
+    /// in `msgs`.
+    ///
+    /// With `D = Sha512`, this is the `ristretto255_XMD:SHA-512_R255MAP_RO_`
+    /// suite of RFC 9380.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use sha2::Sha512;
+    ///
+    /// let dst = b"MyApp-V01-CS01-with-ristretto255_XMD:SHA-512_R255MAP_RO_";
+    /// let P = RistrettoPoint::hash_to_group::<Sha512>(&[b"header", b"body"], dst);
+    /// assert_eq!(P, RistrettoPoint::hash_to_group::<Sha512>(&[b"headerbody"], dst));
+    /// ```
+    pub fn hash_to_group<D>(msgs: &[&[u8]], dst: &[u8]) -> RistrettoPoint
+    where
+        D: Digest + BlockSizeUser + Default,
+    {
+        let mut uniform_bytes = [0u8; 64];
+        hash_to_curve::expand_message_xmd::<D>(msgs, dst, &mut uniform_bytes);
+        RistrettoPoint::from_uniform_bytes(&uniform_bytes)
+    }
+
     /// Construct a `RistrettoPoint` from 64 bytes of data.
     ///
     /// If the input bytes are uniformly distributed, the resulting
@@ -1625,6 +1745,94 @@ mod test {
             let r_0 = FieldElement::from_bytes(&bytes[i]);
             let Q = RistrettoPoint::elligator_ristretto_flavor(&r_0);
             assert_eq!(Q.compress(), encoded_images[i]);
+            assert!(is_elligator_preimage(&Q, &r_0));
+        }
+    }
+
+    /// Check that `r_0` is among the preimages of `P`, and that all of them map to `P`.
+    fn is_elligator_preimage(P: &RistrettoPoint, r_0: &FieldElement) -> bool {
+        let mut r_0_abs = *r_0;
+        r_0_abs.conditional_negate(r_0.is_negative());
+
+        let mut found = false;
+        for preimage in P.elligator_ristretto_flavor_inverse().iter() {
+            if bool::from(preimage.is_some()) {
+                let preimage = preimage.unwrap();
+                assert_eq!(
+                    RistrettoPoint::from_uniform_bytes_single_elligator(&preimage),
+                    *P
+                );
+                found |= preimage == r_0_abs.as_bytes();
+            }
+        }
+        found
+    }
+
+    #[test]
+    fn elligator_inverse_random() {
+        use rand_core::RngCore;
+
+        let mut rng = OsRng;
+        for _ in 0..100 {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            let P = RistrettoPoint::from_uniform_bytes_single_elligator(&bytes);
+            assert!(is_elligator_preimage(&P, &FieldElement::from_bytes(&bytes)));
+        }
+    }
+
+    #[test]
+    fn elligator_inverse_identity() {
+        let id = RistrettoPoint::identity();
+        assert!(is_elligator_preimage(&id, &FieldElement::ZERO));
+        assert!(is_elligator_preimage(&id, &constants::SQRT_ID));
+    }
+
+    /// Embed 16 bytes into a point as in the Lizard encoding, by hashing them and putting them
+    /// in the middle of the hash, then applying the Elligator map once.
+    fn lizard_encode(data: &[u8; 16]) -> RistrettoPoint {
+        use sha2::Digest;
+
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&sha2::Sha256::digest(data));
+        bytes[8..24].copy_from_slice(data);
+        bytes[0] &= 0xfe;
+        bytes[31] &= 0x3f;
+        RistrettoPoint::from_uniform_bytes_single_elligator(&bytes)
+    }
+
+    /// Recover the 16 bytes embedded by `lizard_encode`, if exactly one preimage has the right
+    /// form.
+    fn lizard_decode(P: &RistrettoPoint) -> Option<[u8; 16]> {
+        let mut found = None;
+        let mut n_found = 0;
+        for preimage in P.elligator_ristretto_flavor_inverse().iter() {
+            if bool::from(preimage.is_some()) {
+                let preimage = preimage.unwrap();
+                let mut data = [0u8; 16];
+                data.copy_from_slice(&preimage[8..24]);
+                if lizard_encode(&data) == *P {
+                    found = Some(data);
+                    n_found += 1;
+                }
+            }
+        }
+        if n_found == 1 {
+            found
+        } else {
+            None
+        }
+    }
+
+    #[test]
+    fn elligator_inverse_lizard() {
+        use rand_core::RngCore;
+
+        let mut rng = OsRng;
+        for _ in 0..100 {
+            let mut data = [0u8; 16];
+            rng.fill_bytes(&mut data);
+            assert_eq!(lizard_decode(&lizard_encode(&data)), Some(data));
         }
     }
 
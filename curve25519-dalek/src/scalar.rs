@@ -114,13 +114,14 @@
 use core::borrow::Borrow;
 use core::cmp::{Eq, PartialEq};
 use core::convert::TryInto;
-use core::fmt::Debug;
+use core::fmt::{Debug, Display, LowerHex};
 use core::iter::{Product, Sum};
 use core::ops::Index;
 use core::ops::Neg;
 use core::ops::{Add, AddAssign};
 use core::ops::{Mul, MulAssign};
 use core::ops::{Sub, SubAssign};
+use core::str::FromStr;
 
 use cfg_if::cfg_if;
 
@@ -149,8 +150,19 @@ use subtle::CtOption;
 use zeroize::Zeroize;
 
 use crate::backend;
-#[cfg(not(all(target_os = "zkvm", target_arch = "riscv32")))]
+#[cfg(any(
+    feature = "group-bits",
+    not(any(
+        curve25519_dalek_backend = "risc0",
+        curve25519_dalek_backend = "risc0-emulated"
+    ))
+))]
 use crate::constants;
+use crate::encoding::{self, ParseError};
+
+// Polynomials and Lagrange interpolation over scalars
+#[cfg(feature = "alloc")]
+pub mod poly;
 
 cfg_if! {
     if #[cfg(curve25519_dalek_backend = "fiat")] {
@@ -175,19 +187,22 @@ cfg_if! {
             doc(cfg(all(feature = "fiat_backend", curve25519_dalek_bits = "64")))
         )]
         type UnpackedScalar = backend::serial::fiat_u64::scalar::Scalar52;
-    } else if #[cfg(curve25519_dalek_bits = "64")] {
+    } else if #[cfg(any(
+        curve25519_dalek_backend = "risc0",
+        curve25519_dalek_backend = "risc0-emulated"
+    ))] {
         /// An `UnpackedScalar` represents an element of the field GF(l), optimized for speed.
         ///
         /// This is a type alias for one of the scalar types in the `backend`
         /// module.
-        #[cfg_attr(docsrs, doc(cfg(curve25519_dalek_bits = "64")))]
-        type UnpackedScalar = backend::serial::u64::scalar::Scalar52;
-    } else if #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))] {
+        type UnpackedScalar = backend::serial::risc0::scalar::ScalarR0;
+    } else if #[cfg(curve25519_dalek_bits = "64")] {
         /// An `UnpackedScalar` represents an element of the field GF(l), optimized for speed.
         ///
         /// This is a type alias for one of the scalar types in the `backend`
         /// module.
-        type UnpackedScalar = backend::serial::risc0::scalar::ScalarR0;
+        #[cfg_attr(docsrs, doc(cfg(curve25519_dalek_bits = "64")))]
+        type UnpackedScalar = backend::serial::u64::scalar::Scalar52;
     } else {
         /// An `UnpackedScalar` represents an element of the field GF(l), optimized for speed.
         ///
@@ -254,12 +269,74 @@ impl Scalar {
         s
     }
 
+    /// Construct a `Scalar` by reducing a 256-bit big-endian integer
+    /// modulo the group order \\( \ell \\).
+    pub fn from_be_bytes_mod_order(mut bytes: [u8; 32]) -> Scalar {
+        bytes.reverse();
+        Scalar::from_bytes_mod_order(bytes)
+    }
+
     /// Construct a `Scalar` by reducing a 512-bit little-endian integer
     /// modulo the group order \\( \ell \\).
     pub fn from_bytes_mod_order_wide(input: &[u8; 64]) -> Scalar {
         UnpackedScalar::from_bytes_wide(input).pack()
     }
 
+    /// Construct a `Scalar` by reducing a little-endian integer of any length modulo the group
+    /// order \\( \ell \\).
+    ///
+    /// Inputs longer than 64 bytes are folded in 32 bytes at a time, each step being one wide
+    /// reduction as in [`Scalar::from_bytes_mod_order_wide`]. On the RISC Zero backend, a step
+    /// takes three precompile calls, two for the reduction and one to read the result back out.
+    /// This runs in constant time with respect to the contents of `input`, but not its length.
+    ///
+    /// Specifications that read their hash output as a big-endian integer, as RFC 9380 does,
+    /// need the bytes reversed first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let mut bytes = [0u8; 48];
+    /// bytes[32] = 1;
+    /// // 2^256 mod l
+    /// let expected = Scalar::from_bytes_mod_order([0xff; 32]) + Scalar::ONE;
+    /// assert_eq!(Scalar::from_bytes_mod_order_var(&bytes), expected);
+    /// ```
+    pub fn from_bytes_mod_order_var(input: &[u8]) -> Scalar {
+        let mut wide = [0u8; 64];
+        if input.len() <= 64 {
+            wide[..input.len()].copy_from_slice(input);
+            let s = Scalar::from_bytes_mod_order_wide(&wide);
+
+            #[cfg(feature = "zeroize")]
+            wide.zeroize();
+
+            return s;
+        }
+
+        // Horner's rule in radix 2^256, from the most significant chunk down:
+        // acc = acc * 2^256 + chunk
+        let mut acc = Scalar::ZERO.unpack();
+        for chunk in input.chunks(32).rev() {
+            wide = [0u8; 64];
+            wide[..chunk.len()].copy_from_slice(chunk);
+            wide[32..].copy_from_slice(&acc.as_bytes());
+            acc = UnpackedScalar::from_bytes_wide(&wide);
+        }
+
+        let s = acc.pack();
+
+        #[cfg(feature = "zeroize")]
+        {
+            wide.zeroize();
+            acc.zeroize();
+        }
+
+        s
+    }
+
     /// Attempt to construct a `Scalar` from a canonical byte representation.
     ///
     /// # Return
@@ -299,6 +376,58 @@ impl Debug for Scalar {
     }
 }
 
+impl FromStr for Scalar {
+    type Err = ParseError;
+
+    /// Parse a decimal integer, or a big-endian hexadecimal integer prefixed with `0x`.
+    ///
+    /// The integer must be less than \\( \ell \\); it is not reduced. A decimal integer may not
+    /// have leading zeros, so the unprefixed output of [`LowerHex`] for a scalar below
+    /// \\( 2\^{252} \\), which starts with `0`, is rejected rather than misread as decimal.
+    /// This guard does not cover scalars from \\( 2\^{252} \\) to \\( \ell \\), whose unprefixed
+    /// hex starts with `1` and is read as decimal if it has no digits above `9`. Format with
+    /// `{:#x}` to get a string that parses back. This does not run in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s: Scalar = "0x2a".parse().unwrap();
+    /// assert_eq!(s, Scalar::from(42u8));
+    /// assert_eq!("42".parse::<Scalar>().unwrap(), s);
+    /// assert_eq!(format!("{}", s), "42");
+    /// ```
+    fn from_str(s: &str) -> Result<Scalar, ParseError> {
+        let bytes = match encoding::strip_hex_prefix(s) {
+            (true, digits) => encoding::parse_u256(digits, 16)?,
+            (false, digits) if digits.len() > 1 && digits.starts_with('0') => {
+                return Err(ParseError::LeadingZero)
+            }
+            (false, digits) => encoding::parse_u256(digits, 10)?,
+        };
+        Option::from(Scalar::from_canonical_bytes(bytes)).ok_or(ParseError::OutOfRange)
+    }
+}
+
+impl LowerHex for Scalar {
+    /// Write the integer as 64 big-endian hex digits. This does not run in constant time.
+    ///
+    /// Without the `0x` prefix given by `{:#x}`, [`FromStr`] takes the digits as decimal, so
+    /// the output does not parse back: it is rejected for scalars below \\( 2\^{252} \\), and
+    /// may be misread as a decimal integer for larger ones.
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        encoding::fmt_hex_bytes(&self.to_be_bytes(), f)
+    }
+}
+
+impl Display for Scalar {
+    /// Write the integer in decimal. This does not run in constant time.
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        encoding::fmt_u256_decimal(&self.bytes, f)
+    }
+}
+
 impl Eq for Scalar {}
 impl PartialEq for Scalar {
     fn eq(&self, other: &Self) -> bool {
@@ -383,7 +512,10 @@ impl<'a> Neg for &'a Scalar {
     #[allow(non_snake_case)]
     fn neg(self) -> Scalar {
         cfg_if! {
-            if #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))] {
+            if #[cfg(any(
+                curve25519_dalek_backend = "risc0",
+                curve25519_dalek_backend = "risc0-emulated"
+            ))] {
                 UnpackedScalar::negate(&self.unpack()).pack()
             } else {
                 let self_R = UnpackedScalar::mul_internal(&self.unpack(), &constants::R);
@@ -707,6 +839,24 @@ impl Scalar {
         self.bytes
     }
 
+    /// Convert this `Scalar` to the big-endian encoding of the integer it represents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = Scalar::from(0x0102u64);
+    ///
+    /// assert_eq!(s.to_be_bytes()[30..], [0x01, 0x02]);
+    /// assert_eq!(Scalar::from_be_bytes_mod_order(s.to_be_bytes()), s);
+    /// ```
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = self.bytes;
+        bytes.reverse();
+        bytes
+    }
+
     /// View the little-endian byte encoding of the integer representing this Scalar.
     ///
     /// # Example
@@ -724,6 +874,10 @@ impl Scalar {
 
     /// Given a nonzero `Scalar`, compute its multiplicative inverse.
     ///
+    /// On the R0 backend, the inverse is taken from the host if a
+    /// `risc0_hint` handler is installed, and checked with one
+    /// multiplication.
+    ///
     /// # Warning
     ///
     /// `self` **MUST** be nonzero.  If you cannot
@@ -772,6 +926,9 @@ impl Scalar {
     ///
     /// The product of all inverses is returned.
     ///
+    /// On the R0 backend, the single inversion is taken from the host
+    /// as in [`Scalar::invert`].
+    ///
     /// # Warning
     ///
     /// All input `Scalars` **MUST** be nonzero.  If you cannot
@@ -851,8 +1008,106 @@ impl Scalar {
         ret
     }
 
-    /// Get the bits of the scalar, in little-endian order
-    pub(crate) fn bits_le(&self) -> impl DoubleEndedIterator<Item = bool> + '_ {
+    /// Raise this `Scalar` to the power `exp`, given as four little-endian `u64` limbs.
+    ///
+    /// This runs in constant time with respect to both `self` and `exp`. Use
+    /// [`Scalar::pow_vartime`] when the exponent is public.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let three = Scalar::from(3u8);
+    /// assert_eq!(three.pow(&[4, 0, 0, 0]), Scalar::from(81u8));
+    /// ```
+    pub fn pow(&self, exp: &[u64; 4]) -> Scalar {
+        self.unpack().pow(exp).pack()
+    }
+
+    /// Raise this `Scalar` to the power `exp`, given as four little-endian `u64` limbs.
+    ///
+    /// This runs in variable time with respect to `exp`, which must be public.
+    pub fn pow_vartime(&self, exp: &[u64; 4]) -> Scalar {
+        self.unpack().pow_vartime(exp).pack()
+    }
+
+    /// Compute a square root of this `Scalar` modulo \\( \ell \\), if it has one.
+    ///
+    /// Of the two roots, the one whose canonical encoding is even is returned, so the result is
+    /// the same on every backend. On the R0 backend, the root is taken from the host if a
+    /// `risc0_hint` handler is installed, and checked with one multiplication.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let root = Scalar::from(9u8).sqrt().unwrap();
+    /// assert_eq!(root * root, Scalar::from(9u8));
+    /// assert!(bool::from(Scalar::from(2u8).sqrt().is_none()));
+    /// ```
+    pub fn sqrt(&self) -> CtOption<Scalar> {
+        use subtle::ConditionallyNegatable;
+
+        #[cfg(any(
+            curve25519_dalek_backend = "risc0",
+            curve25519_dalek_backend = "risc0-emulated"
+        ))]
+        if let Some((was_square, root)) = self.unpack().sqrt_hinted() {
+            return CtOption::new(root.pack(), was_square);
+        }
+
+        /// The exponent \\( (\ell + 3) / 8 \\).
+        const L_PLUS_THREE_OVER_EIGHT: [u64; 4] = [
+            0xcb02_4c63_4b9e_ba7e,
+            0x029b_df3b_d45e_f39a,
+            0,
+            0x0200_0000_0000_0000,
+        ];
+        /// \\( \sqrt{-1} \bmod \ell \\), which is \\( 2\^{(\ell - 1) / 4} \\).
+        const SQRT_M1: Scalar = Scalar {
+            bytes: [
+                0xd4, 0x07, 0xbe, 0xeb, 0xdf, 0x75, 0x87, 0xbe, 0xfe, 0x83, 0xce, 0x42, 0x53, 0x56,
+                0xf0, 0x0e, 0x7a, 0xc2, 0xc1, 0xab, 0x60, 0x6d, 0x3d, 0x7d, 0xe7, 0x81, 0x79, 0xe0,
+                0x10, 0x73, 0x4a, 0x09,
+            ],
+        };
+
+        // The roots are compared against the reduced value, since `self` may not be reduced.
+        let a = self.reduce();
+
+        // Since l = 5 (mod 8), r = a^((l+3)/8) satisfies r^2 = ±a whenever a is square,
+        // and multiplying by sqrt(-1) corrects the sign.
+        let root = a.pow(&L_PLUS_THREE_OVER_EIGHT);
+        let root_squared = root * root;
+        let correct_sign = root_squared.ct_eq(&a);
+        let flipped_sign = root_squared.ct_eq(&-a);
+
+        let mut root = Scalar::conditional_select(&root, &(root * SQRT_M1), flipped_sign);
+        let is_odd = Choice::from(root.bytes[0] & 1);
+        root.conditional_negate(is_odd);
+
+        CtOption::new(root, correct_sign | flipped_sign)
+    }
+
+    /// Return whether this `Scalar` is a square modulo \\( \ell \\). Zero is a square.
+    pub fn is_square(&self) -> Choice {
+        self.sqrt().is_some()
+    }
+
+    /// Compute the Legendre symbol \\( (a / \ell) \\) of this `Scalar` \\( a \\): \\(0\\) if it is
+    /// zero, \\(1\\) if it is a nonzero square and \\(-1\\) otherwise.
+    pub fn legendre(&self) -> i8 {
+        let mut symbol = i8::conditional_select(&-1, &1, self.is_square());
+        symbol.conditional_assign(&0, self.ct_eq(&Scalar::ZERO));
+        symbol
+    }
+
+    /// Get the bits of the scalar, in little-endian order.
+    ///
+    /// This always yields exactly 256 bits. By invariant #1 the last one (bit 255) is `false`.
+    pub fn bits_le(&self) -> impl DoubleEndedIterator<Item = bool> + '_ {
         (0..256).map(|i| {
             // As i runs from 0..256, the bottom 3 bits index the bit, while the upper bits index
             // the byte. Since self.bytes is little-endian at the byte level, this iterator is
@@ -933,7 +1188,17 @@ impl Scalar {
     /// If \\( k \mod 2^w\\) is even, we emit \\(0\\), advance 1 bit
     /// and reindex.  In fact, by setting all digits to \\(0\\)
     /// initially, we don't need to emit anything.
-    pub(crate) fn non_adjacent_form(&self, w: usize) -> [i8; 256] {
+    ///
+    /// ## Output
+    ///
+    /// The returned array holds \\(n\_0, \ldots, n\_{255}\\), least significant first, and
+    /// \\(\sum n\_i 2\^i\\) equals `self` as an integer (not reduced mod \\(\ell\\)). Every
+    /// nonzero digit is odd with \\(|n\_i| < 2\^{w-1}\\), and any \\(w\\) consecutive
+    /// digits contain at most one nonzero digit.
+    ///
+    /// The width must satisfy \\(2 \leq w \leq 8\\). This is only checked in debug builds,
+    /// and the output is meaningless for other widths.
+    pub fn non_adjacent_form(&self, w: usize) -> [i8; 256] {
         // required by the NAF definition
         debug_assert!(w >= 2);
         // required so that the NAF digits fit in i8
@@ -996,8 +1261,8 @@ impl Scalar {
     ///
     /// The largest value that can be decomposed like this is just over \\(2^{255}\\). Thus, in
     /// order to not error, the top bit MUST NOT be set, i.e., `Self` MUST be less than
-    /// \\(2^{255}\\).
-    pub(crate) fn as_radix_16(&self) -> [i8; 64] {
+    /// \\(2^{255}\\), which holds for every `Scalar` by invariant #1.
+    pub fn as_radix_16(&self) -> [i8; 64] {
         debug_assert!(self[31] <= 127);
         let mut output = [0i8; 64];
 
@@ -1032,12 +1297,14 @@ impl Scalar {
     }
 
     /// Returns a size hint indicating how many entries of the return
-    /// value of `to_radix_2w` are nonzero.
-    #[cfg(any(feature = "alloc", all(test, feature = "precomputed-tables")))]
-    pub(crate) fn to_radix_2w_size_hint(w: usize) -> usize {
-        debug_assert!(w >= 4);
-        debug_assert!(w <= 8);
-
+    /// value of [`Scalar::as_radix_2w`] may be nonzero. All later entries are zero.
+    ///
+    /// This is \\(\lceil 256/w \rceil\\), plus one for \\(w = 8\\), and never exceeds 64.
+    ///
+    /// ## Panics
+    ///
+    /// Panics unless \\(4 \leq w \leq 8\\).
+    pub fn to_radix_2w_size_hint(w: usize) -> usize {
         let digits_count = match w {
             4..=7 => (256 + w - 1) / w,
             // See comment in to_radix_2w on handling the terminal carry.
@@ -1070,66 +1337,40 @@ impl Scalar {
     /// $$
     /// with \\(-2\^w/2 \leq a_i < 2\^w/2\\) for \\(0 \leq i < (n-1)\\) and \\(-2\^w/2 \leq a_{n-1} \leq 2\^w/2\\).
     ///
-    #[cfg(any(feature = "alloc", feature = "precomputed-tables"))]
-    pub(crate) fn as_radix_2w(&self, w: usize) -> [i8; 64] {
-        debug_assert!(w >= 4);
-        debug_assert!(w <= 8);
+    /// For \\(w = 8\\) a final carry may be stored in an extra digit \\(a\_n \in \\{0, 1\\}\\).
+    /// Entries from [`Scalar::to_radix_2w_size_hint`] onwards are always zero.
+    ///
+    /// ## Panics
+    ///
+    /// Panics unless \\(4 \leq w \leq 8\\).
+    pub fn as_radix_2w(&self, w: usize) -> [i8; 64] {
+        assert!((4..=8).contains(&w), "radix width must be between 4 and 8");
 
         if w == 4 {
             return self.as_radix_16();
         }
 
-        // Scalar formatted as four `u64`s with carry bit packed into the highest bit.
-        let mut scalar64x4 = [0u64; 4];
-        read_le_u64_into(&self.bytes, &mut scalar64x4[0..4]);
-
-        let radix: u64 = 1 << w;
-        let window_mask: u64 = radix - 1;
-
-        let mut carry = 0u64;
         let mut digits = [0i8; 64];
-        let digits_count = (256 + w - 1) / w;
-        #[allow(clippy::needless_range_loop)]
-        for i in 0..digits_count {
-            // Construct a buffer of bits of the scalar, starting at `bit_offset`.
-            let bit_offset = i * w;
-            let u64_idx = bit_offset / 64;
-            let bit_idx = bit_offset % 64;
-
-            // Read the bits from the scalar
-            let bit_buf: u64 = if bit_idx < 64 - w || u64_idx == 3 {
-                // This window's bits are contained in a single u64,
-                // or it's the last u64 anyway.
-                scalar64x4[u64_idx] >> bit_idx
-            } else {
-                // Combine the current u64's bits with the bits from the next u64
-                (scalar64x4[u64_idx] >> bit_idx) | (scalar64x4[1 + u64_idx] << (64 - bit_idx))
-            };
-
-            // Read the actual coefficient value from the window
-            let coef = carry + (bit_buf & window_mask); // coef = [0, 2^r)
-
-            // Recenter coefficients from [0,2^w) to [-2^w/2, 2^w/2)
-            carry = (coef + (radix / 2)) >> w;
-            digits[i] = ((coef as i64) - (carry << w) as i64) as i8;
-        }
-
-        // When 4 < w < 8, we can fold the final carry onto the last digit d,
-        // because d < 2^w/2 so d + carry*2^w = d + 1*2^w < 2^(w+1) < 2^8.
-        //
-        // When w = 8, we can't fit carry*2^w into an i8.  This should
-        // not happen anyways, because the final carry will be 0 for
-        // reduced scalars, but Scalar invariant #1 allows 255-bit scalars.
-        // To handle this, we expand the size_hint by 1 when w=8,
-        // and accumulate the final carry onto another digit.
-        match w {
-            8 => digits[digits_count] += carry as i8,
-            _ => digits[digits_count - 1] += (carry << w) as i8,
+        for (digit, d) in digits.iter_mut().zip(Radix2wDigits::new(self, w)) {
+            *digit = d;
         }
 
         digits
     }
 
+    /// Iterate over the radix \\(2\^w\\) digits of this scalar, least significant first.
+    ///
+    /// This yields the first [`Scalar::to_radix_2w_size_hint`] entries of
+    /// [`Scalar::as_radix_2w`], computing each digit as it is requested.
+    ///
+    /// ## Panics
+    ///
+    /// Panics unless \\(4 \leq w \leq 8\\).
+    pub fn radix_2w_digits(&self, w: usize) -> impl Iterator<Item = i8> {
+        assert!((4..=8).contains(&w), "radix width must be between 4 and 8");
+        Radix2wDigits::new(self, w)
+    }
+
     /// Unpack this `Scalar` to an `UnpackedScalar` for faster arithmetic.
     pub(crate) fn unpack(&self) -> UnpackedScalar {
         UnpackedScalar::from_bytes(&self.bytes)
@@ -1141,7 +1382,10 @@ impl Scalar {
         let x = self.unpack();
 
         cfg_if! {
-            if #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))] {
+            if #[cfg(any(
+                curve25519_dalek_backend = "risc0",
+                curve25519_dalek_backend = "risc0-emulated"
+            ))] {
                 let x_mod_l = UnpackedScalar::reduce(&x);
             } else {
                 let xR = UnpackedScalar::mul_internal(&x, &constants::R);
@@ -1159,6 +1403,84 @@ impl Scalar {
     }
 }
 
+/// The radix \\(2\^w\\) digits of a scalar, computed one at a time, as described in
+/// [`Scalar::as_radix_2w`].
+struct Radix2wDigits {
+    /// The scalar, formatted as four `u64`s.
+    scalar64x4: [u64; 4],
+    w: usize,
+    /// The index of the next digit.
+    i: usize,
+    carry: u64,
+}
+
+impl Radix2wDigits {
+    fn new(scalar: &Scalar, w: usize) -> Radix2wDigits {
+        let mut scalar64x4 = [0u64; 4];
+        read_le_u64_into(&scalar.bytes, &mut scalar64x4[0..4]);
+        Radix2wDigits {
+            scalar64x4,
+            w,
+            i: 0,
+            carry: 0,
+        }
+    }
+}
+
+impl Iterator for Radix2wDigits {
+    type Item = i8;
+
+    fn next(&mut self) -> Option<i8> {
+        let w = self.w;
+        let digits_count = (256 + w - 1) / w;
+        let i = self.i;
+
+        // When w = 8, we can't fit carry*2^w into an i8.  This should
+        // not happen anyways, because the final carry will be 0 for
+        // reduced scalars, but Scalar invariant #1 allows 255-bit scalars.
+        // To handle this, we expand the size_hint by 1 when w=8,
+        // and emit the final carry as another digit.
+        if i >= digits_count {
+            if w == 8 && i == digits_count {
+                self.i += 1;
+                return Some(self.carry as i8);
+            }
+            return None;
+        }
+        self.i += 1;
+
+        // Construct a buffer of bits of the scalar, starting at `bit_offset`.
+        let bit_offset = i * w;
+        let u64_idx = bit_offset / 64;
+        let bit_idx = bit_offset % 64;
+
+        // Read the bits from the scalar
+        let bit_buf: u64 = if bit_idx < 64 - w || u64_idx == 3 {
+            // This window's bits are contained in a single u64,
+            // or it's the last u64 anyway.
+            self.scalar64x4[u64_idx] >> bit_idx
+        } else {
+            // Combine the current u64's bits with the bits from the next u64
+            (self.scalar64x4[u64_idx] >> bit_idx) | (self.scalar64x4[1 + u64_idx] << (64 - bit_idx))
+        };
+
+        // Read the actual coefficient value from the window
+        let radix: u64 = 1 << w;
+        let coef = self.carry + (bit_buf & (radix - 1)); // coef = [0, 2^r)
+
+        // Recenter coefficients from [0,2^w) to [-2^w/2, 2^w/2)
+        self.carry = (coef + (radix / 2)) >> w;
+        let mut digit = ((coef as i64) - (self.carry << w) as i64) as i8;
+
+        // When 4 < w < 8, we can fold the final carry onto the last digit d,
+        // because d < 2^w/2 so d + carry*2^w = d + 1*2^w < 2^(w+1) < 2^8.
+        if w != 8 && i == digits_count - 1 {
+            digit += (self.carry << w) as i8;
+        }
+        Some(digit)
+    }
+}
+
 impl UnpackedScalar {
     /// Pack the limbs of this `UnpackedScalar` into a `Scalar`.
     fn pack(&self) -> Scalar {
@@ -1171,6 +1493,16 @@ impl UnpackedScalar {
     #[rustfmt::skip] // keep alignment of addition chain and squarings
     #[allow(clippy::just_underscores_and_digits)]
     pub fn montgomery_invert(&self) -> UnpackedScalar {
+        // On the R0 backend, `self` is `x*R`, and the inverse `1/(x*R)` from the host becomes
+        // `(1/x)*R` after two multiplications by `R`.
+        #[cfg(any(
+            curve25519_dalek_backend = "risc0",
+            curve25519_dalek_backend = "risc0-emulated"
+        ))]
+        if let Some(inverse) = self.invert_hinted() {
+            return inverse.as_montgomery().as_montgomery();
+        }
+
         // Uses the addition chain from
         // https://briansmith.org/ecc-inversion-addition-chains-01#curve25519_scalar_inversion
         let    _1 = *self;
@@ -1225,12 +1557,128 @@ impl UnpackedScalar {
         y
     }
 
+    /// Compute `self^exp` in constant time, for an exponent given as little-endian `u64` limbs.
+    fn pow(&self, exp: &[u64; 4]) -> UnpackedScalar {
+        cfg_if! {
+            if #[cfg(any(
+                curve25519_dalek_backend = "risc0",
+                curve25519_dalek_backend = "risc0-emulated"
+            ))] {
+                // An R0 multiplication is a single precompile call, half the cost of a
+                // Montgomery multiplication, so the Montgomery form is not used.
+                pow_fixed_window(self, exp, Scalar::ONE.unpack(), Self::mul, Self::square)
+            } else {
+                pow_fixed_window(
+                    &self.as_montgomery(),
+                    exp,
+                    Scalar::ONE.unpack().as_montgomery(),
+                    Self::montgomery_mul,
+                    Self::montgomery_square,
+                )
+                .from_montgomery()
+            }
+        }
+    }
+
+    /// Compute `self^exp` in variable time, for an exponent given as little-endian `u64` limbs.
+    fn pow_vartime(&self, exp: &[u64; 4]) -> UnpackedScalar {
+        cfg_if! {
+            if #[cfg(any(
+                curve25519_dalek_backend = "risc0",
+                curve25519_dalek_backend = "risc0-emulated"
+            ))] {
+                pow_binary_vartime(self, exp, Scalar::ONE.unpack(), Self::mul, Self::square)
+            } else {
+                pow_binary_vartime(
+                    &self.as_montgomery(),
+                    exp,
+                    Scalar::ONE.unpack().as_montgomery(),
+                    Self::montgomery_mul,
+                    Self::montgomery_square,
+                )
+                .from_montgomery()
+            }
+        }
+    }
+
     /// Inverts an UnpackedScalar not in Montgomery form.
     pub fn invert(&self) -> UnpackedScalar {
+        #[cfg(any(
+            curve25519_dalek_backend = "risc0",
+            curve25519_dalek_backend = "risc0-emulated"
+        ))]
+        if let Some(inverse) = self.invert_hinted() {
+            return inverse;
+        }
+
         self.as_montgomery().montgomery_invert().from_montgomery()
     }
 }
 
+/// Compute `x^exp` with 4-bit fixed windows, where `one`, `mul` and `square` all work in the same
+/// representation as `x`. The sequence of operations and table accesses depends on neither `x`
+/// nor `exp`.
+fn pow_fixed_window(
+    x: &UnpackedScalar,
+    exp: &[u64; 4],
+    one: UnpackedScalar,
+    mul: fn(&UnpackedScalar, &UnpackedScalar) -> UnpackedScalar,
+    square: fn(&UnpackedScalar) -> UnpackedScalar,
+) -> UnpackedScalar {
+    // table[i] = x^i
+    let mut table = [one; 16];
+    for i in 1..16 {
+        table[i] = mul(&table[i - 1], x);
+    }
+
+    let nibbles = exp
+        .iter()
+        .rev()
+        .flat_map(|limb| (0..16).rev().map(move |i| (limb >> (4 * i)) & 15));
+
+    let mut acc = one;
+    for nibble in nibbles {
+        for _ in 0..4 {
+            acc = square(&acc);
+        }
+        let mut term = one;
+        for (i, entry) in table.iter().enumerate() {
+            term.conditional_assign(entry, (i as u64).ct_eq(&nibble));
+        }
+        acc = mul(&acc, &term);
+    }
+
+    #[cfg(feature = "zeroize")]
+    table.zeroize();
+
+    acc
+}
+
+/// Compute `x^exp` by binary square-and-multiply, skipping the leading zero bits of `exp`, where
+/// `one`, `mul` and `square` all work in the same representation as `x`.
+fn pow_binary_vartime(
+    x: &UnpackedScalar,
+    exp: &[u64; 4],
+    one: UnpackedScalar,
+    mul: fn(&UnpackedScalar, &UnpackedScalar) -> UnpackedScalar,
+    square: fn(&UnpackedScalar) -> UnpackedScalar,
+) -> UnpackedScalar {
+    let bits = exp
+        .iter()
+        .rev()
+        .flat_map(|limb| (0..64).rev().map(move |i| (limb >> i) & 1 == 1))
+        .skip_while(|bit| !bit);
+
+    let mut acc = one;
+    for bit in bits {
+        acc = square(&acc);
+        if bit {
+            acc = mul(&acc, x);
+        }
+    }
+    acc
+}
+
 #[cfg(feature = "group")]
 impl Field for Scalar {
     const ZERO: Self = Self::ZERO;
@@ -1414,7 +1862,10 @@ pub const fn clamp_integer(mut bytes: [u8; 32]) -> [u8; 32] {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
-    #[cfg(not(all(target_os = "zkvm", target_arch = "riscv32")))]
+    #[cfg(not(any(
+        curve25519_dalek_backend = "risc0",
+        curve25519_dalek_backend = "risc0-emulated"
+    )))]
     use crate::constants;
 
     #[cfg(feature = "alloc")]
@@ -1586,6 +2037,111 @@ pub(crate) mod test {
         }
     }
 
+    /// Interpret little-endian signed digits in radix \\(2^w\\) as a scalar.
+    fn signed_digits_to_scalar(digits: &[i8], w: usize) -> Scalar {
+        let radix = Scalar::from(1u64 << w);
+        digits.iter().rev().fold(Scalar::ZERO, |acc, &d| {
+            let d = if d < 0 {
+                -Scalar::from((-(d as i64)) as u64)
+            } else {
+                Scalar::from(d as u64)
+            };
+            acc * radix + d
+        })
+    }
+
+    fn recoding_test_cases() -> impl Iterator<Item = Scalar> {
+        let mut rng = rand::thread_rng();
+        [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            LARGEST_UNREDUCED_SCALAR,
+            X,
+            Y,
+        ]
+        .into_iter()
+        .chain((0..100).map(move |_| Scalar::random(&mut rng)))
+    }
+
+    #[test]
+    fn non_adjacent_form_invariants() {
+        for x in recoding_test_cases() {
+            for w in 2..=8 {
+                let naf = x.non_adjacent_form(w);
+                for (i, &d) in naf.iter().enumerate() {
+                    if d == 0 {
+                        continue;
+                    }
+                    assert_eq!(d & 1, 1, "nonzero digits are odd");
+                    assert!((d as i32).abs() < 1 << (w - 1));
+                    // No other nonzero digit among the next w - 1 positions
+                    assert!(naf[i + 1..].iter().take(w - 1).all(|&d| d == 0));
+                }
+                assert_eq!(signed_digits_to_scalar(&naf, 1), x.reduce());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn non_adjacent_form_rejects_wide_window() {
+        Scalar::ONE.non_adjacent_form(9);
+    }
+
+    #[test]
+    fn as_radix_16_invariants() {
+        for x in recoding_test_cases() {
+            let digits = x.as_radix_16();
+            assert!(digits[..63].iter().all(|&d| (-8..8).contains(&d)));
+            assert!((-8..=8).contains(&digits[63]));
+            assert_eq!(signed_digits_to_scalar(&digits, 4), x.reduce());
+        }
+    }
+
+    #[test]
+    fn radix_2w_invariants() {
+        for x in recoding_test_cases() {
+            for w in 4..=8 {
+                let digits = x.as_radix_2w(w);
+                let digits_count = Scalar::to_radix_2w_size_hint(w);
+                assert!(digits_count <= 64);
+                assert!(digits[digits_count..].iter().all(|&d| d == 0));
+
+                let bound = 1i16 << (w - 1);
+                assert!(digits
+                    .iter()
+                    .all(|&d| -bound <= d as i16 && d as i16 <= bound));
+                assert_eq!(signed_digits_to_scalar(&digits, w), x.reduce());
+
+                assert!(x
+                    .radix_2w_digits(w)
+                    .eq(digits[..digits_count].iter().copied()));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn as_radix_2w_rejects_narrow_window() {
+        Scalar::ONE.as_radix_2w(3);
+    }
+
+    #[test]
+    fn bits_le_round_trip() {
+        for x in recoding_test_cases() {
+            assert_eq!(x.bits_le().count(), 256);
+            assert_eq!(x.bits_le().last(), Some(false));
+
+            let mut bytes = [0u8; 32];
+            for (i, bit) in x.bits_le().enumerate() {
+                bytes[i >> 3] |= (bit as u8) << (i & 7);
+            }
+            assert_eq!(bytes, x.bytes);
+        }
+    }
+
     #[test]
     fn from_u64() {
         let val: u64 = 0xdeadbeefdeadbeef;
@@ -1743,6 +2299,84 @@ pub(crate) mod test {
         assert_eq!(should_be_one, Scalar::ONE);
     }
 
+    #[test]
+    fn pow() {
+        // l - 2 and l - 1, as little-endian limbs
+        let l_minus_two = [0x5812_631a_5cf5_d3eb, 0x14de_f9de_a2f7_9cd6, 0, 1 << 60];
+        let l_minus_one = [0x5812_631a_5cf5_d3ec, 0x14de_f9de_a2f7_9cd6, 0, 1 << 60];
+
+        assert_eq!(X.pow(&[0; 4]), Scalar::ONE);
+        assert_eq!(X.pow(&[1, 0, 0, 0]), X);
+        assert_eq!(X.pow(&[3, 0, 0, 0]), X * X * X);
+        assert_eq!(X.pow(&l_minus_two), XINV);
+        assert_eq!(X.pow(&l_minus_one), Scalar::ONE);
+        assert_eq!(Scalar::ZERO.pow(&[0; 4]), Scalar::ONE);
+        assert_eq!(Scalar::ZERO.pow(&l_minus_one), Scalar::ZERO);
+
+        // x^(2^64 + 1) = x^(2^64) * x
+        let mut x_2_64 = X;
+        for _ in 0..64 {
+            x_2_64 = x_2_64 * x_2_64;
+        }
+        assert_eq!(X.pow(&[1, 1, 0, 0]), x_2_64 * X);
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let x = Scalar::random(&mut rng);
+            let exp = [
+                rng.next_u64(),
+                rng.next_u64(),
+                rng.next_u64(),
+                rng.next_u64(),
+            ];
+            assert_eq!(x.pow(&exp), x.pow_vartime(&exp));
+        }
+        for exp in [[0; 4], [1, 0, 0, 0], [0, 0, 0, 1 << 63], l_minus_two] {
+            assert_eq!(Y.pow(&exp), Y.pow_vartime(&exp));
+        }
+    }
+
+    #[test]
+    fn sqrt_legendre_and_is_square() {
+        assert_eq!(Scalar::ZERO.sqrt().unwrap(), Scalar::ZERO);
+        assert_eq!(Scalar::ZERO.legendre(), 0);
+        assert!(bool::from(Scalar::ZERO.is_square()));
+
+        assert_eq!(Scalar::from(4u8).sqrt().unwrap(), Scalar::from(2u8));
+        // 9 has the odd root 3, so the even root l - 3 is returned
+        assert_eq!(Scalar::from(9u8).sqrt().unwrap(), -Scalar::from(3u8));
+        // l + 4 is not reduced, but is still the square of 2
+        let mut l_plus_four = crate::constants::BASEPOINT_ORDER_PRIVATE;
+        l_plus_four.bytes[0] += 4;
+        assert_eq!(l_plus_four.sqrt().unwrap(), Scalar::from(2u8));
+        // l = 5 (mod 8), so 2 and -1 are a non-square and a square respectively
+        assert!(bool::from(Scalar::from(2u8).sqrt().is_none()));
+        assert_eq!(Scalar::from(2u8).legendre(), -1);
+        assert_eq!((-Scalar::ONE).legendre(), 1);
+
+        let mut rng = rand::thread_rng();
+        let mut non_squares = 0;
+        for _ in 0..100 {
+            let x = Scalar::random(&mut rng);
+            let square = x * x;
+            let root = square.sqrt().unwrap();
+            assert_eq!(root * root, square);
+            assert_eq!(root.bytes[0] & 1, 0);
+            assert!(root == x || root == -x);
+            assert_eq!(square.legendre(), 1);
+
+            // Exactly one of x and 2x is a square
+            let two_x = x + x;
+            assert_ne!(x.legendre(), two_x.legendre());
+            assert_eq!(bool::from(x.is_square()), x.legendre() == 1);
+            if x.legendre() == -1 {
+                non_squares += 1;
+                assert!(bool::from(x.sqrt().is_none()));
+            }
+        }
+        assert!(non_squares > 0);
+    }
+
     // Negating a scalar twice should result in the original scalar.
     #[allow(non_snake_case)]
     #[test]
@@ -1762,6 +2396,142 @@ pub(crate) mod test {
         assert_eq!(should_be_unpacked.0, unpacked.0);
     }
 
+    #[test]
+    fn be_bytes_round_trip() {
+        let mut be = X.to_bytes();
+        be.reverse();
+        assert_eq!(X.to_be_bytes(), be);
+        assert_eq!(Scalar::from_be_bytes_mod_order(be), X);
+
+        // Unlike parsing, the byte constructor reduces
+        assert_eq!(
+            Scalar::from_be_bytes_mod_order([0xff; 32]),
+            Scalar::from_bytes_mod_order([0xff; 32])
+        );
+    }
+
+    #[test]
+    fn parse_and_format() {
+        let x_dec = "2238329342913194256032495932344128051776374960164957527413114840482143558222";
+        assert_eq!(x_dec.parse::<Scalar>(), Ok(X));
+        assert_eq!(format!("{}", X), x_dec);
+        assert_eq!(
+            format!("{:x}", X).parse::<Scalar>(),
+            Err(ParseError::LeadingZero)
+        );
+        assert_eq!(format!("{:#x}", X).parse::<Scalar>(), Ok(X));
+
+        // Unprefixed hex with only decimal digits must not be misread as decimal
+        let sixteen = Scalar::from(16u8);
+        let sixteen_hex = format!("{:x}", sixteen);
+        assert_eq!(sixteen_hex, format!("{:064x}", 16));
+        assert_eq!(sixteen_hex.parse::<Scalar>(), Err(ParseError::LeadingZero));
+        assert_eq!(format!("0x{}", sixteen_hex).parse::<Scalar>(), Ok(sixteen));
+        assert_eq!("016".parse::<Scalar>(), Err(ParseError::LeadingZero));
+        assert_eq!("00".parse::<Scalar>(), Err(ParseError::LeadingZero));
+        assert_eq!("0".parse::<Scalar>(), Ok(Scalar::ZERO));
+        assert_eq!("0x0010".parse::<Scalar>(), Ok(sixteen));
+        assert_eq!(format!("{:x}", Scalar::ONE), format!("{:064x}", 1));
+
+        // From 2^252 on, unprefixed hex starts with 1 and has no leading zero to reject
+        let mut bytes = [0u8; 32];
+        bytes[31] = 0x10;
+        let two_252 = Scalar::from_canonical_bytes(bytes).unwrap();
+        let two_252_hex = format!("{:x}", two_252);
+        assert_eq!(two_252_hex, format!("1{}", "0".repeat(63)));
+        let misread = two_252_hex.parse::<Scalar>().unwrap();
+        assert_ne!(misread, two_252);
+        assert_eq!(format!("{}", misread), two_252_hex);
+        assert_eq!(
+            format!("{:x}", BASEPOINT_ORDER_MINUS_ONE).parse::<Scalar>(),
+            Err(ParseError::InvalidDigit)
+        );
+        assert_eq!(format!("{:>4}", Scalar::from(7u8)), "   7");
+
+        let l_minus_one_dec =
+            "7237005577332262213973186563042994240857116359379907606001950938285454250988";
+        let l_minus_one_hex = "0x1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ec";
+        assert_eq!(
+            l_minus_one_dec.parse::<Scalar>(),
+            Ok(BASEPOINT_ORDER_MINUS_ONE)
+        );
+        assert_eq!(
+            l_minus_one_hex.parse::<Scalar>(),
+            Ok(BASEPOINT_ORDER_MINUS_ONE)
+        );
+        assert_eq!(format!("{:#x}", BASEPOINT_ORDER_MINUS_ONE), l_minus_one_hex);
+        assert_eq!(format!("{}", BASEPOINT_ORDER_MINUS_ONE), l_minus_one_dec);
+        assert_eq!(format!("{}", Scalar::ZERO), "0");
+        assert_eq!("0X0".parse::<Scalar>(), Ok(Scalar::ZERO));
+
+        // Values at or above l are rejected rather than reduced
+        let l_dec = "7237005577332262213973186563042994240857116359379907606001950938285454250989";
+        assert_eq!(l_dec.parse::<Scalar>(), Err(ParseError::OutOfRange));
+        assert_eq!(
+            format!("0x{}", "f".repeat(64)).parse::<Scalar>(),
+            Err(ParseError::OutOfRange)
+        );
+        assert_eq!(
+            format!("0x1{}", "0".repeat(64)).parse::<Scalar>(),
+            Err(ParseError::OutOfRange)
+        );
+
+        assert_eq!("".parse::<Scalar>(), Err(ParseError::Empty));
+        assert_eq!("0x".parse::<Scalar>(), Err(ParseError::Empty));
+        assert_eq!("+1".parse::<Scalar>(), Err(ParseError::InvalidDigit));
+        assert_eq!(" 1".parse::<Scalar>(), Err(ParseError::InvalidDigit));
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let s = Scalar::random(&mut rng);
+            assert_eq!(format!("{}", s).parse::<Scalar>(), Ok(s));
+            assert_eq!(format!("{:#x}", s).parse::<Scalar>(), Ok(s));
+        }
+    }
+
+    #[test]
+    fn from_bytes_mod_order_var() {
+        // Reference: Horner's rule over single bytes
+        fn reference(bytes: &[u8]) -> Scalar {
+            let radix = Scalar::from(256u16);
+            bytes
+                .iter()
+                .rev()
+                .fold(Scalar::ZERO, |acc, b| acc * radix + Scalar::from(*b))
+        }
+
+        let mut rng = rand::thread_rng();
+        let mut bytes = [0u8; 200];
+        rng.fill_bytes(&mut bytes);
+        for len in 0..=bytes.len() {
+            assert_eq!(
+                Scalar::from_bytes_mod_order_var(&bytes[..len]),
+                reference(&bytes[..len]),
+                "length {}",
+                len
+            );
+        }
+
+        let mut wide = [0u8; 64];
+        wide.copy_from_slice(&bytes[..64]);
+        assert_eq!(
+            Scalar::from_bytes_mod_order_var(&wide),
+            Scalar::from_bytes_mod_order_wide(&wide)
+        );
+        let mut narrow = [0u8; 32];
+        narrow.copy_from_slice(&bytes[..32]);
+        assert_eq!(
+            Scalar::from_bytes_mod_order_var(&narrow),
+            Scalar::from_bytes_mod_order(narrow)
+        );
+
+        // The largest 96-byte input, 2^768 - 1
+        assert_eq!(
+            Scalar::from_bytes_mod_order_var(&[0xff; 96]),
+            reference(&[0xff; 96])
+        );
+    }
+
     #[test]
     fn montgomery_reduce_matches_from_bytes_mod_order_wide() {
         let mut bignum = [0u8; 64];
@@ -1786,7 +2556,10 @@ pub(crate) mod test {
 
         //  (x + 2^256x) * R
         cfg_if! {
-            if #[cfg(all(target_os = "zkvm", target_arch = "riscv32"))] {
+            if #[cfg(any(
+                curve25519_dalek_backend = "risc0",
+                curve25519_dalek_backend = "risc0-emulated"
+            ))] {
                 let montgomery_reduced = UnpackedScalar::reduce(&UnpackedScalar::from_bytes_wide(&bignum));
             } else {
                 let interim =
@@ -1886,7 +2659,6 @@ pub(crate) mod test {
         }
     }
 
-    #[cfg(feature = "precomputed-tables")]
     fn test_pippenger_radix_iter(scalar: Scalar, w: usize) {
         let digits_count = Scalar::to_radix_2w_size_hint(w);
         let digits = scalar.as_radix_2w(w);
@@ -1911,7 +2683,6 @@ pub(crate) mod test {
     }
 
     #[test]
-    #[cfg(feature = "precomputed-tables")]
     fn test_pippenger_radix() {
         use core::iter;
         // For each valid radix it tests that 1000 random-ish scalars can be restored
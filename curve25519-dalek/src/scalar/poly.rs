@@ -0,0 +1,259 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Polynomials over \\( \mathbb Z / \ell \mathbb Z \\) and Lagrange interpolation, as used by
+//! Shamir secret sharing and threshold protocols.
+//!
+//! Evaluation and interpolation run in constant time with respect to the coefficients and the
+//! \\(y\\)-values, so they can be used on secret shares. The \\(x\\)-coordinates of the points
+//! are usually participant indices; they are only checked to be distinct, which is not done in
+//! constant time.
+//!
+//! # Example
+//!
+//! ```
+//! use curve25519_dalek::scalar::{poly, Scalar};
+//!
+//! // f(x) = 42 + 3x + 5x^2, a 3-of-n sharing of the secret 42
+//! let f = poly::Polynomial::new(vec![Scalar::from(42u8), Scalar::from(3u8), Scalar::from(5u8)]);
+//! let shares: Vec<(Scalar, Scalar)> = [2u8, 5, 7]
+//!     .iter()
+//!     .map(|&i| (Scalar::from(i), f.evaluate(&Scalar::from(i))))
+//!     .collect();
+//!
+//! assert_eq!(poly::interpolate(&shares, &Scalar::ZERO), Scalar::from(42u8));
+//! ```
+
+use alloc::vec::Vec;
+
+#[cfg(any(test, feature = "rand_core"))]
+use rand_core::CryptoRngCore;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::scalar::Scalar;
+
+/// A polynomial with `Scalar` coefficients, stored from the constant term upwards.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Polynomial {
+    coefficients: Vec<Scalar>,
+}
+
+impl Polynomial {
+    /// Construct the polynomial \\( \sum\_i c\_i x\^i \\) from its coefficients \\( c\_i \\),
+    /// starting with the constant term.
+    pub fn new(coefficients: Vec<Scalar>) -> Polynomial {
+        Polynomial { coefficients }
+    }
+
+    /// Generate a polynomial of the given degree with the given constant term and the other
+    /// coefficients chosen uniformly at random.
+    ///
+    /// Sharing a secret among parties so that any `degree + 1` of them can recover it means
+    /// taking the secret as the constant term and handing out evaluations at distinct nonzero
+    /// points.
+    #[cfg(any(test, feature = "rand_core"))]
+    pub fn random<R: CryptoRngCore + ?Sized>(
+        rng: &mut R,
+        constant_term: Scalar,
+        degree: usize,
+    ) -> Polynomial {
+        let mut coefficients = Vec::with_capacity(degree + 1);
+        coefficients.push(constant_term);
+        coefficients.extend((0..degree).map(|_| Scalar::random(rng)));
+        Polynomial { coefficients }
+    }
+
+    /// View the coefficients of this polynomial, starting with the constant term.
+    pub fn coefficients(&self) -> &[Scalar] {
+        &self.coefficients
+    }
+
+    /// Return the constant term of this polynomial, i.e. its value at zero.
+    pub fn constant_term(&self) -> Scalar {
+        self.coefficients.first().copied().unwrap_or(Scalar::ZERO)
+    }
+
+    /// Evaluate this polynomial at `x` with Horner's rule.
+    ///
+    /// This runs in constant time with respect to `x` and the coefficients.
+    pub fn evaluate(&self, x: &Scalar) -> Scalar {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Scalar::ZERO, |acc, c| acc * x + c)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for Polynomial {
+    fn zeroize(&mut self) {
+        self.coefficients.zeroize();
+    }
+}
+
+/// Compute the Lagrange coefficients \\( \lambda\_i \\) for the \\(x\\)-coordinates `xs`,
+/// evaluated at `at`, so that any polynomial \\(f\\) of degree less than `xs.len()` satisfies
+/// \\( f(at) = \sum\_i \lambda\_i f(x\_i) \\).
+///
+/// The coefficients are
+/// $$
+/// \lambda\_i = \prod\_{j \neq i} \frac{at - x\_j}{x\_i - x\_j},
+/// $$
+/// computed with a single batched inversion. Use `Scalar::ZERO` for `at` to recover a shared
+/// secret.
+///
+/// # Panics
+///
+/// If the entries of `xs` are not distinct.
+pub fn lagrange_coefficients(xs: &[Scalar], at: &Scalar) -> Vec<Scalar> {
+    let n = xs.len();
+
+    // Denominators: prod_{j != i} (x_i - x_j)
+    let mut denominators: Vec<Scalar> = xs
+        .iter()
+        .enumerate()
+        .map(|(i, x_i)| {
+            xs.iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(Scalar::ONE, |acc, (_, x_j)| acc * (x_i - x_j))
+        })
+        .collect();
+    let inverse_product = Scalar::batch_invert(&mut denominators);
+    assert!(
+        n == 0 || inverse_product != Scalar::ZERO,
+        "Lagrange interpolation needs distinct x-coordinates"
+    );
+
+    // Numerators: prod_{j != i} (at - x_j), from prefix and suffix products so that `at` may
+    // equal one of the x_i
+    let differences: Vec<Scalar> = xs.iter().map(|x_j| at - x_j).collect();
+    let mut suffix = Scalar::ONE;
+    let mut coefficients = vec![Scalar::ZERO; n];
+    for i in (0..n).rev() {
+        coefficients[i] = suffix;
+        suffix *= differences[i];
+    }
+    let mut prefix = Scalar::ONE;
+    for ((coefficient, difference), inverse) in coefficients
+        .iter_mut()
+        .zip(differences.iter())
+        .zip(denominators.iter())
+    {
+        *coefficient = prefix * *coefficient * inverse;
+        prefix *= difference;
+    }
+
+    coefficients
+}
+
+/// Evaluate at `at` the unique polynomial of degree less than `points.len()` that passes through
+/// the given \\((x, y)\\) points.
+///
+/// With `Scalar::ZERO` for `at`, this recovers a secret from its Shamir shares. This runs in
+/// constant time with respect to the \\(y\\)-values.
+///
+/// # Panics
+///
+/// If the \\(x\\)-coordinates of `points` are not distinct.
+pub fn interpolate(points: &[(Scalar, Scalar)], at: &Scalar) -> Scalar {
+    let xs: Vec<Scalar> = points.iter().map(|(x, _)| *x).collect();
+    lagrange_coefficients(&xs, at)
+        .iter()
+        .zip(points.iter())
+        .map(|(lambda, (_, y))| lambda * y)
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn evaluate_matches_naive_sum() {
+        let mut rng = rand::thread_rng();
+        let f = Polynomial::random(&mut rng, Scalar::from(7u8), 5);
+        assert_eq!(f.coefficients().len(), 6);
+        assert_eq!(f.constant_term(), Scalar::from(7u8));
+        assert_eq!(f.evaluate(&Scalar::ZERO), Scalar::from(7u8));
+
+        let x = Scalar::random(&mut rng);
+        let mut power = Scalar::ONE;
+        let mut expected = Scalar::ZERO;
+        for c in f.coefficients() {
+            expected += c * power;
+            power *= x;
+        }
+        assert_eq!(f.evaluate(&x), expected);
+
+        let empty = Polynomial::new(Vec::new());
+        assert_eq!(empty.evaluate(&x), Scalar::ZERO);
+        assert_eq!(empty.constant_term(), Scalar::ZERO);
+    }
+
+    #[test]
+    fn lagrange_coefficients_recover_polynomial() {
+        let mut rng = rand::thread_rng();
+        let secret = Scalar::random(&mut rng);
+        let f = Polynomial::random(&mut rng, secret, 3);
+        let xs: Vec<Scalar> = [1u8, 3, 4, 9].iter().map(|&i| Scalar::from(i)).collect();
+        let ys: Vec<Scalar> = xs.iter().map(|x| f.evaluate(x)).collect();
+
+        let at_zero = lagrange_coefficients(&xs, &Scalar::ZERO);
+        let recovered: Scalar = at_zero.iter().zip(ys.iter()).map(|(l, y)| l * y).sum();
+        assert_eq!(recovered, secret);
+
+        // The coefficients sum to one, as they reproduce the constant polynomial 1
+        let at = Scalar::random(&mut rng);
+        let lambdas = lagrange_coefficients(&xs, &at);
+        assert_eq!(lambdas.iter().sum::<Scalar>(), Scalar::ONE);
+        let value: Scalar = lambdas.iter().zip(ys.iter()).map(|(l, y)| l * y).sum();
+        assert_eq!(value, f.evaluate(&at));
+
+        // At one of the x_i, the coefficients select the matching y_i
+        let lambdas = lagrange_coefficients(&xs, &xs[2]);
+        for (i, lambda) in lambdas.iter().enumerate() {
+            let expected = if i == 2 { Scalar::ONE } else { Scalar::ZERO };
+            assert_eq!(*lambda, expected);
+        }
+
+        assert!(lagrange_coefficients(&[], &at).is_empty());
+        assert_eq!(lagrange_coefficients(&xs[..1], &at), vec![Scalar::ONE]);
+    }
+
+    #[test]
+    fn interpolate_shares() {
+        let mut rng = rand::thread_rng();
+        let secret = Scalar::random(&mut rng);
+        let f = Polynomial::random(&mut rng, secret, 2);
+        let shares: Vec<(Scalar, Scalar)> = (1u8..=5)
+            .map(|i| (Scalar::from(i), f.evaluate(&Scalar::from(i))))
+            .collect();
+
+        // Any three shares determine the secret
+        assert_eq!(interpolate(&shares[..3], &Scalar::ZERO), secret);
+        assert_eq!(interpolate(&shares[2..], &Scalar::ZERO), secret);
+        assert_eq!(
+            interpolate(&[shares[0], shares[2], shares[4]], &Scalar::ZERO),
+            secret
+        );
+        // More shares than needed interpolate the same polynomial
+        assert_eq!(interpolate(&shares, &Scalar::ZERO), secret);
+        // Too few shares do not
+        assert_ne!(interpolate(&shares[..2], &Scalar::ZERO), secret);
+
+        let at = Scalar::random(&mut rng);
+        assert_eq!(interpolate(&shares[1..4], &at), f.evaluate(&at));
+    }
+
+    #[test]
+    #[should_panic]
+    fn lagrange_coefficients_reject_repeated_x() {
+        let xs = [Scalar::ONE, Scalar::from(2u8), Scalar::ONE];
+        lagrange_coefficients(&xs, &Scalar::ZERO);
+    }
+}
@@ -86,3 +86,17 @@ OUT=build_9.txt
 env RUSTFLAGS="--cfg curve25519_dalek_diagnostics=\"build\" --cfg curve25519_dalek_backend=\"serial\" --cfg curve25519_dalek_bits=\"32\"" cargo build > "$OUT" 2>&1
 match_and_report "curve25519_dalek_backend is 'serial'" "$OUT"
 match_and_report "curve25519_dalek_bits is '32'" "$OUT"
+
+# risc0 override outside of the zkVM
+cargo clean
+OUT=build_10.txt
+env RUSTFLAGS="--cfg curve25519_dalek_diagnostics=\"build\" --cfg curve25519_dalek_backend=\"risc0\"" cargo build > "$OUT" 2>&1
+# This overide must fail the compilation since the precompile is not available
+match_and_report "Could not override curve25519_dalek_backend to risc0" "$OUT"
+
+# risc0-emulated override with default 64 bit naive host assumption
+cargo clean
+OUT=build_11.txt
+env RUSTFLAGS="--cfg curve25519_dalek_diagnostics=\"build\" --cfg curve25519_dalek_backend=\"risc0-emulated\"" cargo build > "$OUT" 2>&1
+match_and_report "curve25519_dalek_backend is 'risc0-emulated'" "$OUT"
+match_and_report "curve25519_dalek_bits is '64'" "$OUT"
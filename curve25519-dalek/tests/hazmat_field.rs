@@ -0,0 +1,98 @@
+//! Tests for the field arithmetic exposed by the `hazmat` feature, run with
+//! `cargo test --features hazmat`.
+
+use curve25519_dalek::field::FieldElement;
+use subtle::ConditionallySelectable;
+
+/// The little-endian encoding of p - 1 = -1.
+const MINUS_ONE_BYTES: [u8; 32] = [
+    0xec, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f,
+];
+
+fn small(n: u8) -> FieldElement {
+    let mut bytes = [0u8; 32];
+    bytes[0] = n;
+    FieldElement::from_bytes(&bytes)
+}
+
+#[test]
+fn bytes_round_trip() {
+    let minus_one = FieldElement::from_bytes(&MINUS_ONE_BYTES);
+    assert_eq!(minus_one.to_bytes(), MINUS_ONE_BYTES);
+    assert_eq!(minus_one, -&FieldElement::ONE);
+    assert_eq!((minus_one + FieldElement::ONE).to_bytes(), [0u8; 32]);
+
+    // p itself is not canonical and is reduced to zero.
+    let mut p_bytes = MINUS_ONE_BYTES;
+    p_bytes[0] += 1;
+    assert!(bool::from(FieldElement::from_bytes(&p_bytes).is_zero()));
+}
+
+#[test]
+fn arithmetic() {
+    let two = small(2);
+    let three = small(3);
+    assert_eq!(two + three, small(5));
+    assert_eq!(three - two, FieldElement::ONE);
+    assert_eq!(two * three, small(6));
+    assert_eq!(three * three.invert(), FieldElement::ONE);
+    assert!(bool::from(FieldElement::ZERO.invert().is_zero()));
+
+    assert!(!bool::from(two.is_negative()));
+    assert!(bool::from((-&two).is_negative()));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn batch_invert() {
+    let mut elements = [small(2), FieldElement::ZERO, small(7)];
+    FieldElement::batch_invert(&mut elements);
+    assert_eq!(elements[0], small(2).invert());
+    assert!(bool::from(elements[1].is_zero()));
+    assert_eq!(elements[2], small(7).invert());
+}
+
+#[test]
+fn sqrt_ratio_i() {
+    // 4/1 is square with nonnegative root 2.
+    let (was_square, root) = FieldElement::sqrt_ratio_i(&small(4), &FieldElement::ONE);
+    assert!(bool::from(was_square));
+    assert_eq!(root, small(2));
+
+    // 2 is not a square mod p, so sqrt(i * 2) is returned instead.
+    let (was_square, root) = FieldElement::sqrt_ratio_i(&small(2), &FieldElement::ONE);
+    assert!(!bool::from(was_square));
+    assert!(!bool::from(root.is_negative()));
+    // root^2 = 2i, so root^4 = -4.
+    assert_eq!(root * root * root * root, -&small(4));
+}
+
+#[test]
+fn sqrt() {
+    // 3 is odd, i.e. negative, so the root returned is -3.
+    assert_eq!(small(9).sqrt().unwrap(), -&small(3));
+    assert!(bool::from(small(9).is_square()));
+    assert!(bool::from(small(2).sqrt().is_none()));
+    assert!(!bool::from(small(2).is_square()));
+
+    // is_square uses Euler's criterion rather than sqrt, so check that they agree.
+    for n in 0..32 {
+        let x = small(n);
+        assert_eq!(
+            bool::from(x.is_square()),
+            bool::from(x.sqrt().is_some()),
+            "{}",
+            n
+        );
+        assert_eq!(bool::from((-x).is_square()), bool::from(x.is_square()));
+    }
+}
+
+#[test]
+fn conditional_select() {
+    let a = small(1);
+    let b = small(9);
+    assert_eq!(FieldElement::conditional_select(&a, &b, 0.into()), a);
+    assert_eq!(FieldElement::conditional_select(&a, &b, 1.into()), b);
+}
@@ -0,0 +1,195 @@
+//! Tests for the host hints of the RISC Zero backend, run with
+//! `RUSTFLAGS='--cfg curve25519_dalek_backend="risc0-emulated"' cargo test --test risc0_hint`.
+//!
+//! The handler and the `risc0_fault` flag are global, so everything is checked from a single test
+//! to keep other tests from running while a misbehaving handler is installed, or after the flag
+//! has been set.
+
+#![cfg(curve25519_dalek_backend = "risc0-emulated")]
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use curve25519_dalek::constants::{ED25519_BASEPOINT_POINT, RISTRETTO_BASEPOINT_POINT};
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use curve25519_dalek::risc0_fault;
+use curve25519_dalek::risc0_hint::{self, Handler, Query};
+use curve25519_dalek::ristretto::CompressedRistretto;
+use curve25519_dalek::{EdwardsPoint, RistrettoPoint, Scalar};
+
+static QUERIES: AtomicU32 = AtomicU32::new(0);
+
+/// A cooperative host which counts the queries it answers.
+fn counting_host(query: &Query) -> [u8; 32] {
+    QUERIES.fetch_add(1, Ordering::Relaxed);
+    risc0_hint::answer(query)
+}
+
+/// A host whose answers are always off by one bit.
+fn lying_host(query: &Query) -> [u8; 32] {
+    let mut answer = risc0_hint::answer(query);
+    answer[0] ^= 1;
+    answer
+}
+
+/// Count the queries made by `f`.
+fn queries<T>(f: impl FnOnce() -> T) -> (T, u32) {
+    QUERIES.store(0, Ordering::Relaxed);
+    let result = f();
+    (result, QUERIES.load(Ordering::Relaxed))
+}
+
+/// The encodings of `points`, followed by encodings of points with `x = 0`, which take a square
+/// root of zero, and encodings which are not on the curve.
+fn encodings(points: &[CompressedEdwardsY]) -> impl Iterator<Item = [u8; 32]> + '_ {
+    let mut one = [0u8; 32];
+    one[0] = 1;
+    let mut minus_one = [0xffu8; 32];
+    minus_one[0] = 0xec;
+    minus_one[31] = 0x7f;
+    points.iter().map(|point| point.to_bytes()).chain([
+        one,
+        minus_one,
+        [0u8; 32],
+        [2u8; 32],
+        [0x55u8; 32],
+    ])
+}
+
+/// The results of `sqrt_ratio_i`, `sqrt` and `is_square` on small values, which include the
+/// special cases of zero.
+#[cfg(feature = "hazmat")]
+fn sqrt_ratios() -> Vec<(bool, [u8; 32])> {
+    use curve25519_dalek::field::FieldElement;
+
+    let values: Vec<FieldElement> = (0u64..6)
+        .map(FieldElement::from)
+        .chain([-FieldElement::ONE])
+        .collect();
+    let mut results = Vec::new();
+    for u in &values {
+        for v in &values {
+            let (was_square, root) = FieldElement::sqrt_ratio_i(u, v);
+            results.push((bool::from(was_square), root.to_bytes()));
+        }
+        let root = u.sqrt();
+        let root_bytes = root.unwrap_or(FieldElement::ZERO).to_bytes();
+        results.push((bool::from(root.is_some()), root_bytes));
+        results.push((bool::from(u.is_square()), [0u8; 32]));
+    }
+    results
+}
+
+/// The results of `Scalar::sqrt` on `scalars` and zero.
+fn scalar_sqrts(scalars: &[Scalar]) -> Vec<(bool, Scalar)> {
+    scalars
+        .iter()
+        .chain([Scalar::ZERO].iter())
+        .map(|scalar| {
+            let root = scalar.sqrt();
+            (bool::from(root.is_some()), root.unwrap_or(Scalar::ZERO))
+        })
+        .collect()
+}
+
+#[test]
+fn hints() {
+    let scalars: Vec<Scalar> = (1u64..8).map(Scalar::from).collect();
+    let points: Vec<EdwardsPoint> = scalars
+        .iter()
+        .map(|s| ED25519_BASEPOINT_POINT * s)
+        .collect();
+    let ristretto: Vec<RistrettoPoint> = scalars
+        .iter()
+        .map(|s| RISTRETTO_BASEPOINT_POINT * s)
+        .collect();
+
+    // The results without a handler, computed in the guest.
+    let compressed: Vec<_> = points.iter().map(EdwardsPoint::compress).collect();
+    let montgomery: Vec<_> = points.iter().map(EdwardsPoint::to_montgomery).collect();
+    let doubled = RistrettoPoint::double_and_compress_batch(&ristretto);
+    let decompressed: Vec<_> = encodings(&compressed)
+        .map(|encoding| CompressedEdwardsY(encoding).decompress())
+        .collect();
+    let decompressed_ristretto: Vec<_> = encodings(&compressed)
+        .map(|encoding| CompressedRistretto(encoding).decompress())
+        .collect();
+    #[cfg(feature = "hazmat")]
+    let sqrt_ratios = sqrt_ratios();
+    let inverses: Vec<Scalar> = scalars.iter().map(Scalar::invert).collect();
+    let roots = scalar_sqrts(&scalars);
+    let mut batch = scalars.clone();
+    let batch_product = Scalar::batch_invert(&mut batch);
+
+    // A cooperative host is asked once per inversion or square root and gives the same results.
+    risc0_hint::set_handler(Some(&(counting_host as Handler)));
+    for (i, point) in points.iter().enumerate() {
+        assert_eq!(queries(|| point.compress()), (compressed[i], 1));
+        assert_eq!(queries(|| point.to_montgomery()), (montgomery[i], 1));
+    }
+    assert_eq!(
+        queries(|| RistrettoPoint::double_and_compress_batch(&ristretto)),
+        (doubled.clone(), 1)
+    );
+    for (i, encoding) in encodings(&compressed).enumerate() {
+        let edwards = queries(|| CompressedEdwardsY(encoding).decompress());
+        assert_eq!(edwards, (decompressed[i], 1));
+        let ristretto = queries(|| CompressedRistretto(encoding).decompress());
+        assert_eq!(ristretto.0, decompressed_ristretto[i]);
+    }
+    #[cfg(feature = "hazmat")]
+    assert_eq!(
+        queries(self::sqrt_ratios),
+        (sqrt_ratios.clone(), sqrt_ratios.len() as u32)
+    );
+    for (i, scalar) in scalars.iter().enumerate() {
+        assert_eq!(queries(|| scalar.invert()), (inverses[i], 1));
+    }
+    assert_eq!(queries(|| Scalar::ZERO.invert()), (Scalar::ZERO, 1));
+    assert_eq!(
+        queries(|| scalar_sqrts(&scalars)),
+        (roots.clone(), roots.len() as u32)
+    );
+    let mut hinted_batch = scalars.clone();
+    assert_eq!(
+        queries(|| Scalar::batch_invert(&mut hinted_batch)),
+        (batch_product, 1)
+    );
+    assert_eq!(hinted_batch, batch);
+
+    // Nothing so far was a fault.
+    assert!(!risc0_fault::host_misbehaved());
+    assert_eq!(points[0].try_compress(), Some(compressed[0]));
+
+    // A host which lies is caught, and the guest computes the results itself.
+    risc0_hint::set_handler(Some(&(lying_host as Handler)));
+    for (i, point) in points.iter().enumerate() {
+        assert_eq!(point.compress(), compressed[i]);
+        assert_eq!(point.to_montgomery(), montgomery[i]);
+    }
+    assert_eq!(
+        RistrettoPoint::double_and_compress_batch(&ristretto),
+        doubled
+    );
+    for (i, encoding) in encodings(&compressed).enumerate() {
+        assert_eq!(CompressedEdwardsY(encoding).decompress(), decompressed[i]);
+        assert_eq!(
+            CompressedRistretto(encoding).decompress(),
+            decompressed_ristretto[i]
+        );
+    }
+    #[cfg(feature = "hazmat")]
+    assert_eq!(self::sqrt_ratios(), sqrt_ratios);
+    for (i, scalar) in scalars.iter().enumerate() {
+        assert_eq!(scalar.invert(), inverses[i]);
+    }
+    assert_eq!(scalar_sqrts(&scalars), roots);
+    let mut lied_batch = scalars.clone();
+    assert_eq!(Scalar::batch_invert(&mut lied_batch), batch_product);
+    assert_eq!(lied_batch, batch);
+
+    // The lies are recorded, and the flag stays set once the host cooperates again.
+    risc0_hint::set_handler(None);
+    assert!(risc0_fault::host_misbehaved());
+    assert_eq!(points[0].try_compress(), None);
+    assert!(!points[0].eq_compressed(&compressed[0]));
+}
@@ -0,0 +1,76 @@
+//! Tests for the `risc0-op-counters` feature, run with
+//! `RUSTFLAGS='--cfg curve25519_dalek_backend="risc0-emulated"' cargo test --features risc0-op-counters`.
+//!
+//! The counters are global, so every pinned count is checked from the single test in this binary,
+//! which no other test can run alongside. New pins belong in `op_counts` rather than in a test of
+//! their own.
+
+#![cfg(curve25519_dalek_backend = "risc0-emulated")]
+
+use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+use curve25519_dalek::risc0_op_counters::{self, Counts, OpCounts};
+use curve25519_dalek::Scalar;
+
+#[test]
+fn op_counts() {
+    let a = Scalar::from(1234567u64);
+    let b = Scalar::from(7654321u64);
+
+    // Scalar multiplication makes one precompile multiplication, then reduces the result to pack it.
+    risc0_op_counters::reset();
+    let ab = a * b;
+    assert_eq!(
+        risc0_op_counters::read(),
+        Counts {
+            field: OpCounts::default(),
+            scalar: OpCounts {
+                mul: 1,
+                reduction: 1,
+                ..OpCounts::default()
+            },
+        }
+    );
+
+    // Scalar addition makes no precompile calls apart from the reduction when packing.
+    risc0_op_counters::reset();
+    let _ = ab + a;
+    let counts = risc0_op_counters::read();
+    assert_eq!(counts.scalar.add_with_correction, 1);
+    assert_eq!(counts.scalar.precompile_calls(), 1);
+
+    // Point arithmetic only touches the field. The exact counts are pinned, so that a change in
+    // the cost of the formulas, the inversion or the square root shows up here.
+    risc0_op_counters::reset();
+    let point = ED25519_BASEPOINT_POINT + ED25519_BASEPOINT_POINT;
+    assert_eq!(risc0_op_counters::read(), field_counts(13, 0, 9, 0));
+
+    // Three doublings.
+    risc0_op_counters::reset();
+    let _ = point.mul_by_cofactor();
+    assert_eq!(risc0_op_counters::read(), field_counts(19, 9, 15, 0));
+
+    // Compression inverts Z with the pow22501 addition chain.
+    risc0_op_counters::reset();
+    let compressed = point.compress();
+    assert_eq!(risc0_op_counters::read(), field_counts(13, 254, 0, 0));
+
+    // Decompression takes a square root with the pow_p58 addition chain.
+    risc0_op_counters::reset();
+    let _ = compressed.decompress();
+    assert_eq!(risc0_op_counters::read(), field_counts(26, 255, 2, 1));
+
+    risc0_op_counters::reset();
+    assert_eq!(risc0_op_counters::read(), Counts::default());
+}
+
+fn field_counts(mul: u32, square: u32, add_with_correction: u32, reduction: u32) -> Counts {
+    Counts {
+        field: OpCounts {
+            mul,
+            square,
+            add_with_correction,
+            reduction,
+        },
+        scalar: OpCounts::default(),
+    }
+}
@@ -217,9 +217,9 @@ impl VerifyingKey {
     // Helper function for verification. Computes the _expected_ R component of the signature. The
     // caller compares this to the real R component.  If `context.is_some()`, this does the
     // prehashed variant of the computation using its contents.
-    // Note that this returns the compressed form of R and the caller does a byte comparison. This
-    // means that all our verification functions do not accept non-canonically encoded R values.
-    // See the validation criteria blog post for more details:
+    // Note that the caller compares with `EdwardsPoint::eq_compressed`, which matches only the
+    // canonical encoding of R. This means that all our verification functions do not accept
+    // non-canonically encoded R values. See the validation criteria blog post for more details:
     //     https://hdevalence.ca/blog/2020-10-04-its-25519am
     #[allow(non_snake_case)]
     fn recompute_R<CtxDigest>(
@@ -227,14 +227,14 @@ impl VerifyingKey {
         context: Option<&[u8]>,
         signature: &InternalSignature,
         M: &[u8],
-    ) -> CompressedEdwardsY
+    ) -> EdwardsPoint
     where
         CtxDigest: Digest<OutputSize = U64>,
     {
         let k = Self::compute_challenge::<CtxDigest>(context, &signature.R, &self.compressed, M);
         let minus_A: EdwardsPoint = -self.point;
         // Recall the (non-batched) verification equation: -[k]A + [s]B = R
-        EdwardsPoint::vartime_double_scalar_mul_basepoint(&k, &(minus_A), &signature.s).compress()
+        EdwardsPoint::vartime_double_scalar_mul_basepoint(&k, &(minus_A), &signature.s)
     }
 
     /// The ordinary non-batched Ed25519 verification check, rejecting non-canonical R values. (see
@@ -255,7 +255,7 @@ impl VerifyingKey {
         let signature = InternalSignature::try_from(signature)?;
 
         let expected_R = self.recompute_R::<CtxDigest>(None, &signature, message);
-        if expected_R == signature.R {
+        if expected_R.eq_compressed(&signature.R) {
             Ok(())
         } else {
             Err(InternalError::Verify.into())
@@ -292,7 +292,7 @@ impl VerifyingKey {
         let message = prehashed_message.finalize();
         let expected_R = self.recompute_R::<CtxDigest>(Some(ctx), &signature, &message);
 
-        if expected_R == signature.R {
+        if expected_R.eq_compressed(&signature.R) {
             Ok(())
         } else {
             Err(InternalError::Verify.into())
@@ -406,18 +406,15 @@ impl VerifyingKey {
     ) -> Result<(), SignatureError> {
         let signature = InternalSignature::try_from(signature)?;
 
-        let signature_R = signature
-            .R
-            .decompress()
-            .ok_or_else(|| SignatureError::from(InternalError::Verify))?;
-
-        // Logical OR is fine here as we're not trying to be constant time.
-        if signature_R.is_small_order() || self.point.is_small_order() {
+        if self.point.is_small_order() {
             return Err(InternalError::Verify.into());
         }
 
         let expected_R = self.recompute_R::<Sha512>(None, &signature, message);
-        if expected_R == signature.R {
+        // The signature's R must decode to expected_R, so checking the order of expected_R
+        // rejects a small-order R without decompressing it a second time.
+        // Logical AND is fine here as we're not trying to be constant time.
+        if !expected_R.is_small_order() && expected_R.eq_compressed(&signature.R) {
             Ok(())
         } else {
             Err(InternalError::Verify.into())
@@ -467,20 +464,17 @@ impl VerifyingKey {
             "The context must not be longer than 255 octets."
         );
 
-        let signature_R = signature
-            .R
-            .decompress()
-            .ok_or_else(|| SignatureError::from(InternalError::Verify))?;
-
-        // Logical OR is fine here as we're not trying to be constant time.
-        if signature_R.is_small_order() || self.point.is_small_order() {
+        if self.point.is_small_order() {
             return Err(InternalError::Verify.into());
         }
 
         let message = prehashed_message.finalize();
         let expected_R = self.recompute_R::<Sha512>(Some(ctx), &signature, &message);
 
-        if expected_R == signature.R {
+        // The signature's R must decode to expected_R, so checking the order of expected_R
+        // rejects a small-order R without decompressing it a second time.
+        // Logical AND is fine here as we're not trying to be constant time.
+        if !expected_R.is_small_order() && expected_R.eq_compressed(&signature.R) {
             Ok(())
         } else {
             Err(InternalError::Verify.into())